@@ -0,0 +1,13 @@
+use anchor::blocking::Client;
+use std::error::Error;
+
+const IMAGE_REF: &str = "nginx:latest";
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let client = Client::new_anonymous()?;
+
+    let outcome = client.pull_image(IMAGE_REF)?;
+    println!("Pulled {IMAGE_REF}: {outcome}");
+
+    Ok(())
+}
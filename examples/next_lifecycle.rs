@@ -1,9 +1,9 @@
-use anchor::prelude::{Client, MountType, ResourceStatus, get_ecr_credentials};
-use std::error::Error;
+use anchor::prelude::{Client, MountType, PortMapping, ResourceStatus, get_ecr_credentials};
+use std::{collections::HashMap, error::Error};
 
 const IMAGE_REF: &str = "939027885851.dkr.ecr.eu-west-2.amazonaws.com/uncertainty-engine-add-node:latest";
 const CONTAINER_NAME: &str = "node-add";
-const PORT_MAPPINGS: &[(u16, u16)] = &[(8000, 8000)];
+const PORT_MAPPINGS: &[PortMapping] = &[PortMapping::tcp(8000, 8000)];
 const ENV_VARS: &[(&str, &str)] = &[];
 const MOUNTS: &[MountType] = &[];
 
@@ -18,19 +18,26 @@ async fn main() -> Result<(), Box<dyn Error>> {
             println!("Pulling image...");
             client.pull_image(IMAGE_REF).await?;
         }
-        ResourceStatus::Available => {
+        ResourceStatus::Downloaded => {
             println!("Building container...");
-            client
-                .build_container(IMAGE_REF, CONTAINER_NAME, PORT_MAPPINGS, ENV_VARS, MOUNTS)
+            let env_vars: HashMap<String, String> = ENV_VARS
+                .iter()
+                .map(|(key, value)| ((*key).to_string(), (*value).to_string()))
+                .collect();
+            let _id = client
+                .build_container(IMAGE_REF, CONTAINER_NAME, PORT_MAPPINGS, &env_vars, MOUNTS)
                 .await?;
         }
-        ResourceStatus::Built => {
+        ResourceStatus::Built | ResourceStatus::Exited { .. } => {
             println!("Starting container...");
             client.start_container(CONTAINER_NAME).await?;
         }
         ResourceStatus::Running => {
             println!("Container is already running.");
         }
+        ResourceStatus::Paused | ResourceStatus::Restarting | ResourceStatus::Removing | ResourceStatus::Dead => {
+            println!("Container is {status}, leaving it alone.");
+        }
     }
 
     Ok(())
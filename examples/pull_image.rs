@@ -0,0 +1,14 @@
+use anchor::prelude::Client;
+use std::error::Error;
+
+const IMAGE_REF: &str = "nginx:latest";
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let client = Client::new_anonymous().await?;
+
+    let outcome = client.pull_image(IMAGE_REF).await?;
+    println!("Pulled {IMAGE_REF}: {outcome}");
+
+    Ok(())
+}
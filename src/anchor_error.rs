@@ -1,15 +1,29 @@
-use std::fmt::{Display, Formatter};
+use bollard::errors::Error as BollardError;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+use crate::resource_kind::ResourceKind;
 
 /// Result type for Anchor operations, encapsulating `AnchorError`.
 pub type AnchorResult<T> = Result<T, AnchorError>;
 
+/// Underlying error that caused an `AnchorError`.
+pub type ErrorSource = Box<dyn Error + Send + Sync>;
+
 /// Errors that can occur when interacting with the Docker daemon.
 #[derive(Debug)]
 pub enum AnchorError {
     /// Docker is not installed on the system.
     DockerNotInstalled,
     /// Error connecting to the Docker daemon.
-    ConnectionError(String),
+    ConnectionError {
+        /// A message describing the error.
+        message: String,
+        /// The underlying error, if any.
+        source: Option<ErrorSource>,
+    },
     /// Error retrieving ECR credentials.
     ECRCredentialsError(String),
     /// Error related to a specific Docker image.
@@ -18,6 +32,8 @@ pub enum AnchorError {
         image: String,
         /// A message describing the error.
         message: String,
+        /// The underlying error, if any.
+        source: Option<ErrorSource>,
     },
     /// Error related to a specific Docker container.
     ContainerError {
@@ -25,17 +41,48 @@ pub enum AnchorError {
         container: String,
         /// A message describing the error.
         message: String,
+        /// The underlying error, if any.
+        source: Option<ErrorSource>,
+    },
+    /// Error related to a specific Docker volume.
+    VolumeError {
+        /// The name of the Docker volume associated with the error.
+        volume: String,
+        /// A message describing the error.
+        message: String,
+        /// The underlying error, if any.
+        source: Option<ErrorSource>,
+    },
+    /// The Docker resource an operation acted on does not exist.
+    NotFound {
+        /// The kind of the missing resource.
+        kind: ResourceKind,
+        /// The name, ID or reference of the missing resource.
+        name: String,
+        /// The underlying error, if any.
+        source: Option<ErrorSource>,
     },
     /// IO stream error.
     IoStreamError(String),
+    /// An operation did not complete within its time limit.
+    TimeoutError(String),
 }
 
 impl AnchorError {
+    /// Create a `ConnectionError` with a message
+    pub fn connection_error<M: AsRef<str>>(message: M) -> Self {
+        Self::ConnectionError {
+            message: message.as_ref().to_string(),
+            source: None,
+        }
+    }
+
     /// Create an `ImageError` with context
     pub fn image_error<S: AsRef<str>, M: AsRef<str>>(image: S, message: M) -> Self {
         Self::ImageError {
             image: image.as_ref().to_string(),
             message: message.as_ref().to_string(),
+            source: None,
         }
     }
 
@@ -44,6 +91,71 @@ impl AnchorError {
         Self::ContainerError {
             container: container.as_ref().to_string(),
             message: message.as_ref().to_string(),
+            source: None,
+        }
+    }
+
+    /// Create a `VolumeError` with context
+    pub fn volume_error<S: AsRef<str>, M: AsRef<str>>(volume: S, message: M) -> Self {
+        Self::VolumeError {
+            volume: volume.as_ref().to_string(),
+            message: message.as_ref().to_string(),
+            source: None,
+        }
+    }
+
+    /// Create a `NotFound` error for a missing resource
+    pub fn not_found<S: AsRef<str>>(kind: ResourceKind, name: S) -> Self {
+        Self::NotFound {
+            kind,
+            name: name.as_ref().to_string(),
+            source: None,
+        }
+    }
+
+    /// Attach the underlying error to a `ConnectionError`, `ImageError`, `ContainerError`, `VolumeError` or `NotFound`
+    ///
+    /// Other variants are returned unchanged.
+    #[must_use]
+    pub fn with_source<E: Error + Send + Sync + 'static>(mut self, err: E) -> Self {
+        if let Self::ConnectionError { source, .. }
+        | Self::ImageError { source, .. }
+        | Self::ContainerError { source, .. }
+        | Self::VolumeError { source, .. }
+        | Self::NotFound { source, .. } = &mut self
+        {
+            *source = Some(Box::new(err));
+        }
+        self
+    }
+
+    /// Attach a Docker API error as the underlying error, reporting a missing resource as `NotFound`
+    ///
+    /// If Docker reports that a container, image, volume or network does not exist, a `NotFound` error
+    /// for that resource is returned instead, keeping the Docker error as its source. Otherwise this is
+    /// the same as `with_source`.
+    #[must_use]
+    pub fn with_docker_source(self, err: BollardError) -> Self {
+        match missing_resource(&err) {
+            Some((kind, name)) => Self::not_found(kind, name).with_source(err),
+            None => self.with_source(err),
+        }
+    }
+
+    /// Returns true if the error is a `NotFound` error, e.g. to treat removing a missing container as success
+    #[must_use]
+    pub const fn is_not_found(&self) -> bool {
+        matches!(self, Self::NotFound { .. })
+    }
+
+    /// Returns the HTTP status code of the Docker API response that caused the error, if any
+    ///
+    /// Useful for reacting to specific failures, e.g. 404 (not found) or 409 (conflict).
+    #[must_use]
+    pub fn docker_status_code(&self) -> Option<u16> {
+        match self.source()?.downcast_ref::<BollardError>()? {
+            BollardError::DockerResponseServerError { status_code, .. } => Some(*status_code),
+            _ => None,
         }
     }
 }
@@ -54,13 +166,14 @@ impl From<std::io::Error> for AnchorError {
     }
 }
 
-impl From<bollard::errors::Error> for AnchorError {
-    fn from(err: bollard::errors::Error) -> Self {
-        match err {
-            bollard::errors::Error::DockerResponseServerError { message, .. } => Self::ConnectionError(message),
-            bollard::errors::Error::IOError { err: _ } => Self::ConnectionError(format!("IO Error: {err}")),
-            _ => Self::ConnectionError(err.to_string()),
-        }
+impl From<BollardError> for AnchorError {
+    fn from(err: BollardError) -> Self {
+        let message = match &err {
+            BollardError::DockerResponseServerError { message, .. } => message.clone(),
+            BollardError::IOError { err: _ } => format!("IO Error: {err}"),
+            _ => err.to_string(),
+        };
+        Self::connection_error(message).with_docker_source(err)
     }
 }
 
@@ -68,17 +181,96 @@ impl Display for AnchorError {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::DockerNotInstalled => write!(fmt, "Docker is not installed"),
-            Self::ConnectionError(message) => write!(fmt, "Docker connection error: {message}"),
+            Self::ConnectionError { message, .. } => write!(fmt, "Docker connection error: {message}"),
             Self::ECRCredentialsError(message) => write!(fmt, "Docker ECR credentials error: {message}"),
-            Self::ImageError { image, message } => {
+            Self::ImageError { image, message, .. } => {
                 write!(fmt, "Docker image error for '{image}': {message}")
             }
-            Self::ContainerError { container, message } => {
+            Self::ContainerError { container, message, .. } => {
                 write!(fmt, "Docker container error for '{container}': {message}")
             }
+            Self::VolumeError { volume, message, .. } => {
+                write!(fmt, "Docker volume error for '{volume}': {message}")
+            }
+            Self::NotFound { kind, name, .. } => write!(fmt, "Docker {kind} '{name}' not found"),
             Self::IoStreamError(message) => write!(fmt, "Docker io stream error: {message}"),
+            Self::TimeoutError(message) => write!(fmt, "Docker timeout error: {message}"),
         }
     }
 }
 
-impl std::error::Error for AnchorError {}
+impl Error for AnchorError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::ConnectionError { source, .. }
+            | Self::ImageError { source, .. }
+            | Self::ContainerError { source, .. }
+            | Self::VolumeError { source, .. }
+            | Self::NotFound { source, .. } => source.as_deref().map(|source| -> &(dyn Error + 'static) { source }),
+            _ => None,
+        }
+    }
+}
+
+/// Finds the kind and name of the missing resource in Docker's 404 response.
+///
+/// Docker names the missing resource in its message, e.g. "No such container: web" or "network web not found".
+/// Returns `None` for other errors, including 404s about something else, such as a path inside a container.
+fn missing_resource(err: &BollardError) -> Option<(ResourceKind, &str)> {
+    let BollardError::DockerResponseServerError {
+        status_code: 404,
+        message,
+    } = err
+    else {
+        return None;
+    };
+    let lowercase = message.to_ascii_lowercase();
+    let (kind, name) = if let Some(name) = lowercase.strip_prefix("no such container: ") {
+        (ResourceKind::Container, &message[message.len() - name.len()..])
+    } else if let Some(name) = lowercase.strip_prefix("no such image: ") {
+        (ResourceKind::Image, &message[message.len() - name.len()..])
+    } else if let Some(name) = lowercase
+        .strip_prefix("get ")
+        .and_then(|rest| rest.strip_suffix(": no such volume"))
+    {
+        (ResourceKind::Volume, &message[4..4 + name.len()])
+    } else if let Some(name) = lowercase
+        .strip_prefix("network ")
+        .and_then(|rest| rest.strip_suffix(" not found"))
+    {
+        (ResourceKind::Network, &message[8..8 + name.len()])
+    } else {
+        return None;
+    };
+    Some((kind, name.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn docker_not_found_keeps_its_source() {
+        let err = BollardError::DockerResponseServerError {
+            status_code: 404,
+            message: "No such container: web".to_owned(),
+        };
+        let err = AnchorError::container_error("web", "Failed to start container").with_docker_source(err);
+
+        assert!(matches!(&err, AnchorError::NotFound { kind: ResourceKind::Container, name, .. } if name == "web"));
+        assert!(err.source().is_some());
+        assert_eq!(err.docker_status_code(), Some(404));
+    }
+
+    #[test]
+    fn with_source_does_not_change_the_variant() {
+        let err = BollardError::DockerResponseServerError {
+            status_code: 404,
+            message: "No such container: web".to_owned(),
+        };
+        let err = AnchorError::container_error("web", "Failed to start container").with_source(err);
+
+        assert!(matches!(err, AnchorError::ContainerError { .. }));
+        assert_eq!(err.docker_status_code(), Some(404));
+    }
+}
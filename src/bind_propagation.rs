@@ -0,0 +1,73 @@
+use bollard::models::MountBindOptionsPropagationEnum;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Display, Formatter, Result},
+    str::FromStr,
+};
+
+/// Mount propagation mode of a bind mount, controlling whether mounts made under it are shared with the host
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BindPropagation {
+    /// Recursively private: no mount events propagate in either direction (Docker's default)
+    RPrivate,
+    /// Private: no mount events propagate, without applying to nested mounts
+    Private,
+    /// Recursively shared: mount events propagate in both directions
+    RShared,
+    /// Shared: mount events propagate in both directions, without applying to nested mounts
+    Shared,
+    /// Recursively slave: mount events propagate from the host into the container only
+    RSlave,
+    /// Slave: mount events propagate from the host into the container only, without applying to nested mounts
+    Slave,
+}
+
+impl BindPropagation {
+    /// Returns the Docker name of the propagation mode (e.g. "rshared")
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::RPrivate => "rprivate",
+            Self::Private => "private",
+            Self::RShared => "rshared",
+            Self::Shared => "shared",
+            Self::RSlave => "rslave",
+            Self::Slave => "slave",
+        }
+    }
+}
+
+impl FromStr for BindPropagation {
+    type Err = String;
+
+    fn from_str(propagation: &str) -> std::result::Result<Self, Self::Err> {
+        match propagation {
+            "rprivate" => Ok(Self::RPrivate),
+            "private" => Ok(Self::Private),
+            "rshared" => Ok(Self::RShared),
+            "shared" => Ok(Self::Shared),
+            "rslave" => Ok(Self::RSlave),
+            "slave" => Ok(Self::Slave),
+            _ => Err(format!("Unknown bind propagation mode '{propagation}'")),
+        }
+    }
+}
+
+impl From<BindPropagation> for MountBindOptionsPropagationEnum {
+    fn from(propagation: BindPropagation) -> Self {
+        match propagation {
+            BindPropagation::RPrivate => Self::RPRIVATE,
+            BindPropagation::Private => Self::PRIVATE,
+            BindPropagation::RShared => Self::RSHARED,
+            BindPropagation::Shared => Self::SHARED,
+            BindPropagation::RSlave => Self::RSLAVE,
+            BindPropagation::Slave => Self::SLAVE,
+        }
+    }
+}
+
+impl Display for BindPropagation {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        write!(fmt, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,132 @@
+//! Synchronous facade over the async `Client`, for scripts and CLI tools that do not run an async runtime.
+
+use bollard::auth::DockerCredentials;
+use tokio::runtime::{Builder, Handle, Runtime};
+
+use crate::{
+    anchor_error::{AnchorError, AnchorResult},
+    client::Client as AsyncClient,
+    container_metrics::ContainerMetrics,
+    container_spec::ContainerSpec,
+    pull_outcome::PullOutcome,
+    resource_status::ResourceStatus,
+};
+
+/// Blocking Docker client, running each operation of an async `Client` to completion on its own runtime
+///
+/// Takes the same specs and returns the same types as the async API. It owns a current-thread Tokio runtime,
+/// so it must not be created or used from within an async context.
+#[derive(Debug)]
+pub struct Client {
+    /// Async client the operations are delegated to
+    inner: AsyncClient,
+    /// Runtime the async operations are driven on
+    runtime: Runtime,
+}
+
+impl Client {
+    /// Creates a new blocking Docker client with the provided credentials.
+    ///
+    /// # Arguments
+    /// * `credentials` - Docker registry credentials for authenticated pulls
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ConnectionError` if called from within an async runtime, if the runtime cannot
+    /// be created, or if the Docker daemon is unreachable.
+    pub fn new(credentials: DockerCredentials) -> AnchorResult<Self> {
+        Self::connect(AsyncClient::new(credentials))
+    }
+
+    /// Creates a new blocking Docker client without registry credentials.
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ConnectionError` if called from within an async runtime, if the runtime cannot
+    /// be created, or if the Docker daemon is unreachable.
+    pub fn new_anonymous() -> AnchorResult<Self> {
+        Self::connect(AsyncClient::new_anonymous())
+    }
+
+    /// Wraps an async client created by `connect` in a new current-thread runtime.
+    fn connect<F: Future<Output = AnchorResult<AsyncClient>>>(connect: F) -> AnchorResult<Self> {
+        if Handle::try_current().is_ok() {
+            return Err(AnchorError::connection_error(
+                "The blocking client cannot be used from within an async runtime, use the async `Client` instead",
+            ));
+        }
+        let runtime = Builder::new_current_thread().enable_all().build().map_err(|err| {
+            AnchorError::connection_error(format!("Failed to create runtime for blocking client: {err}")).with_source(err)
+        })?;
+        let inner = runtime.block_on(connect)?;
+        Ok(Self { inner, runtime })
+    }
+
+    /// Downloads a Docker image from a registry, see `Client::pull_image`.
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ImageError` if the download fails.
+    pub fn pull_image<S: AsRef<str>>(&self, image_reference: S) -> AnchorResult<PullOutcome> {
+        self.runtime.block_on(self.inner.pull_image(image_reference))
+    }
+
+    /// Creates a new Docker container from an image using a `ContainerSpec`, see `Client::build_container_with_spec`.
+    ///
+    /// # Returns
+    /// The container ID of the created container.
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ContainerError` if the spec is invalid, if the image doesn't exist, or if creation fails.
+    pub fn build_container<S: AsRef<str>, T: AsRef<str>>(
+        &self,
+        image_reference: S,
+        container_name: T,
+        spec: &ContainerSpec,
+    ) -> AnchorResult<String> {
+        self.runtime
+            .block_on(self.inner.build_container_with_spec(image_reference, container_name, spec))
+    }
+
+    /// Starts an existing Docker container, see `Client::start_container`.
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ContainerError` if the container doesn't exist or fails to start.
+    pub fn start_container<S: AsRef<str>>(&self, container_name_or_id: S) -> AnchorResult<()> {
+        self.runtime.block_on(self.inner.start_container(container_name_or_id))
+    }
+
+    /// Stops a running Docker container gracefully, see `Client::stop_container`.
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ContainerError` if the container cannot be stopped.
+    pub fn stop_container<S: AsRef<str>>(&self, container_name_or_id: S) -> AnchorResult<()> {
+        self.runtime.block_on(self.inner.stop_container(container_name_or_id))
+    }
+
+    /// Forcefully removes a Docker container, see `Client::remove_container`.
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ContainerError` if the container cannot be removed.
+    pub fn remove_container<S: AsRef<str>>(&self, container_name_or_id: S) -> AnchorResult<()> {
+        self.runtime.block_on(self.inner.remove_container(container_name_or_id))
+    }
+
+    /// Gets the lifecycle status of an image and its container, see `Client::get_resource_status`.
+    ///
+    /// # Errors
+    /// Returns `AnchorError` if the image or container list cannot be retrieved.
+    pub fn get_resource_status<S: AsRef<str>, T: AsRef<str>>(
+        &self,
+        image_reference: S,
+        container_name_or_id: T,
+    ) -> AnchorResult<ResourceStatus> {
+        self.runtime
+            .block_on(self.inner.get_resource_status(image_reference, container_name_or_id))
+    }
+
+    /// Gets runtime metrics of a container, see `Client::get_container_metrics`.
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ContainerError` if the container doesn't exist or metrics cannot be retrieved.
+    pub fn get_container_metrics<S: AsRef<str>>(&self, container_name_or_id: S) -> AnchorResult<ContainerMetrics> {
+        self.runtime.block_on(self.inner.get_container_metrics(container_name_or_id))
+    }
+}
@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+/// Base of the units used to format byte counts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ByteBase {
+    /// Binary units, each 1024 times the last (KiB, MiB, ...)
+    #[default]
+    Base1024,
+    /// SI units, each 1000 times the last (kB, MB, ...)
+    Base1000,
+}
+
+/// Options for formatting byte counts with `format_bytes_opts`
+///
+/// The default matches `format_bytes`: binary units with one decimal place, labelled KB, MB, etc.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ByteFormat {
+    /// Base of the units
+    pub base: ByteBase,
+    /// Number of decimal places shown for sizes of a kilobyte or more
+    pub precision: u8,
+    /// Whether binary units are labelled KiB, MiB, etc. rather than KB, MB (SI units are always kB, MB)
+    pub iec_labels: bool,
+}
+
+impl ByteFormat {
+    /// Binary units labelled KiB, MiB, etc., with one decimal place
+    #[must_use]
+    pub const fn iec() -> Self {
+        Self {
+            base: ByteBase::Base1024,
+            precision: 1,
+            iec_labels: true,
+        }
+    }
+
+    /// SI units labelled kB, MB, etc., with one decimal place
+    #[must_use]
+    pub const fn si() -> Self {
+        Self {
+            base: ByteBase::Base1000,
+            precision: 1,
+            iec_labels: false,
+        }
+    }
+
+    /// Sets the number of decimal places shown
+    #[must_use]
+    pub const fn with_precision(mut self, precision: u8) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Returns the unit labels, from bytes upwards
+    #[must_use]
+    pub const fn unit_labels(&self) -> [&'static str; 5] {
+        match (self.base, self.iec_labels) {
+            (ByteBase::Base1024, false) => ["B", "KB", "MB", "GB", "TB"],
+            (ByteBase::Base1024, true) => ["B", "KiB", "MiB", "GiB", "TiB"],
+            (ByteBase::Base1000, _) => ["B", "kB", "MB", "GB", "TB"],
+        }
+    }
+
+    /// Returns the number of bytes in each unit above the last
+    #[must_use]
+    pub const fn multiplier(&self) -> u64 {
+        match self.base {
+            ByteBase::Base1024 => 1024,
+            ByteBase::Base1000 => 1000,
+        }
+    }
+}
+
+impl Default for ByteFormat {
+    fn default() -> Self {
+        Self {
+            base: ByteBase::Base1024,
+            precision: 1,
+            iec_labels: false,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use bollard::models::ChangeType;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result};
+
+/// Kind of change made to a path in a container's filesystem
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ChangeKind {
+    /// Path was created since the container was started from its image
+    Added,
+    /// Path was removed from the image's filesystem
+    Deleted,
+    /// Path exists in the image but its contents or metadata changed
+    Modified,
+}
+
+impl From<ChangeType> for ChangeKind {
+    fn from(kind: ChangeType) -> Self {
+        // Docker reports 0 for modified, 1 for added and 2 for deleted paths
+        match kind {
+            ChangeType::_0 => Self::Modified,
+            ChangeType::_1 => Self::Added,
+            ChangeType::_2 => Self::Deleted,
+        }
+    }
+}
+
+impl Display for ChangeKind {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Added => write!(fmt, "A"),
+            Self::Deleted => write!(fmt, "D"),
+            Self::Modified => write!(fmt, "C"),
+        }
+    }
+}
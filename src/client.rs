@@ -1,31 +1,94 @@
+#[cfg(feature = "remote")]
+use bollard::API_DEFAULT_VERSION;
+#[expect(
+    deprecated,
+    reason = "bollard 0.19.0's remove_volume does not accept the generated query parameters"
+)]
+use bollard::volume::RemoveVolumeOptions;
 use bollard::{
     Docker,
     auth::DockerCredentials,
+    errors::Error as BollardError,
     models::{
-        ContainerCreateBody, ContainerSummary, HostConfig, ImageSummary, Mount, MountBindOptions, MountTypeEnum,
-        MountVolumeOptions, PortBinding,
+        ContainerCreateBody, ContainerStatsResponse, ContainerSummary, ContainerSummaryStateEnum,
+        DeviceMapping as DockerDeviceMapping, DeviceRequest as DockerDeviceRequest, HealthConfig, HostConfig, ImageSummary,
+        Mount, MountBindOptions, MountBindOptionsPropagationEnum, MountTypeEnum, MountVolumeOptions,
+        MountVolumeOptionsDriverConfig, PortBinding, VolumeCreateOptions,
     },
     query_parameters::{
-        CreateContainerOptionsBuilder, CreateImageOptionsBuilder, InspectContainerOptions, ListContainersOptionsBuilder,
-        ListImagesOptionsBuilder, RemoveContainerOptionsBuilder, RemoveImageOptionsBuilder, StartContainerOptionsBuilder,
-        StopContainerOptionsBuilder,
+        CreateContainerOptionsBuilder, CreateImageOptionsBuilder, DownloadFromContainerOptionsBuilder, EventsOptionsBuilder,
+        InspectContainerOptions, KillContainerOptionsBuilder, ListContainersOptionsBuilder, ListImagesOptionsBuilder,
+        ListVolumesOptions, LogsOptionsBuilder, RemoveContainerOptionsBuilder, RemoveImageOptionsBuilder,
+        RenameContainerOptionsBuilder, StartContainerOptionsBuilder, StatsOptionsBuilder, StopContainerOptionsBuilder,
+        TopOptions, UploadToContainerOptionsBuilder, WaitContainerOptionsBuilder,
     },
 };
 use chrono::{DateTime, Utc};
-use futures_util::StreamExt;
+use futures_util::{
+    Stream, StreamExt,
+    future::{Either, select},
+    stream,
+};
 use std::{
     collections::HashMap,
-    time::{Duration, SystemTime, UNIX_EPOCH},
+    path::Path,
+    pin::pin,
+    process::{Command, Stdio},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
+use tokio::time::{sleep, timeout as with_timeout};
 
 use crate::{
     anchor_error::{AnchorError, AnchorResult},
+    client_builder::ClientBuilder,
+    container_filter::ContainerFilter,
+    container_inspect::ContainerInspect,
     container_metrics::ContainerMetrics,
+    container_signal::ContainerSignal,
+    container_spec::ContainerSpec,
+    container_stats::ContainerStats,
+    daemon_version::DaemonVersion,
+    disk_usage::DiskUsageReport,
+    docker_event::DockerEvent,
+    docker_info::DockerInfo,
+    event_filter::EventFilter,
+    filesystem_change::FilesystemChange,
+    format::parse_docker_time,
     health_status::HealthStatus,
+    image_filter::ImageFilter,
+    image_info::ImageInfo,
+    log_entry::LogEntry,
+    log_line_buffer::LogLineBuffer,
     mount_type::MountType,
+    platform::Platform,
+    port_mapping::PortMapping,
+    process_info::ProcessInfo,
+    pull_outcome::PullOutcome,
+    pull_progress::PullProgress,
+    resource_kind::ResourceKind,
     resource_status::ResourceStatus,
+    validation::{is_valid_container_name, is_valid_image_reference},
+    volume_info::VolumeInfo,
 };
 
+/// Default timeout in seconds for requests to the Docker daemon, matching bollard's own default.
+pub const DEFAULT_TIMEOUT_SECS: u64 = 120;
+
+/// Number of trailing log lines attached to the error when a container exits unsuccessfully.
+const FAILURE_LOG_LINES: u64 = 20;
+
+/// Default number of trailing log lines attached to the error when a container fails to start.
+pub const DEFAULT_STARTUP_LOG_LINES: u64 = 50;
+
+/// Time between container state checks in `wait_running`.
+const WAIT_RUNNING_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Maximum number of containers queried at once when collecting metrics in bulk.
+const MAX_CONCURRENT_METRICS: usize = 8;
+
+/// Maximum number of containers started or stopped, or images pulled, at once by bulk operations.
+const MAX_CONCURRENT_BULK_OPERATIONS: usize = 8;
+
 /// Client for interacting with the Docker daemon.
 #[derive(Debug)]
 pub struct Client {
@@ -33,8 +96,14 @@ pub struct Client {
     docker: Docker,
     /// Registry credentials for authenticated image operations
     credentials: DockerCredentials,
-    /// Platform string (e.g., "linux/amd64") of the Docker host
-    platform: String,
+    /// Platform (e.g., "linux/amd64") of the Docker host
+    platform: Platform,
+    /// Time after starting a container within which an exit is reported as a start failure
+    startup_grace_period: Duration,
+    /// Number of trailing log lines attached to the error when a container fails to start
+    startup_log_lines: u64,
+    /// Total memory of the Docker host in bytes, used for unlimited containers' memory percentage
+    host_memory: Option<u64>,
 }
 
 impl Client {
@@ -49,29 +118,195 @@ impl Client {
     /// Returns `AnchorError::ConnectionError` if Docker daemon is unreachable.
     pub async fn new(credentials: DockerCredentials) -> AnchorResult<Self> {
         // Try to connect to Docker daemon
-        let docker = Docker::connect_with_local_defaults().map_err(|err| AnchorError::ConnectionError(err.to_string()))?;
+        let docker = Docker::connect_with_local_defaults()
+            .map_err(|err| AnchorError::connection_error(err.to_string()).with_source(err))?;
+
+        Self::from_docker(docker, credentials, None).await
+    }
+
+    /// Creates a new Docker client without registry credentials.
+    ///
+    /// Suitable when only public images (e.g. `nginx`, `postgres`) are pulled; pulls are made as an
+    /// anonymous client, so Docker Hub's anonymous rate limits apply.
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ConnectionError` if Docker daemon is unreachable.
+    pub async fn new_anonymous() -> AnchorResult<Self> {
+        Self::new(DockerCredentials::default()).await
+    }
+
+    /// Creates a new Docker client connected through a Unix socket (or Windows named pipe) at a custom path.
+    ///
+    /// Useful for rootless Docker (e.g., `~/.docker/run/docker.sock`) or Podman's Docker-compatible socket.
+    ///
+    /// # Arguments
+    /// * `credentials` - Docker registry credentials for authenticated pulls
+    /// * `socket_path` - Path to the daemon socket, with or without a `unix://` prefix
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ConnectionError` if the socket doesn't exist or the daemon is unreachable.
+    pub async fn new_with_socket_path<P: AsRef<Path>>(credentials: DockerCredentials, socket_path: P) -> AnchorResult<Self> {
+        ClientBuilder::new()
+            .credentials(credentials)
+            .socket_path(socket_path.as_ref())
+            .build()
+            .await
+    }
+
+    /// Creates a new Docker client using the connection described by the `DOCKER_HOST` environment variable.
+    ///
+    /// Dispatches on the scheme of `DOCKER_HOST`: `unix://` (or `npipe://` on Windows) connects through a socket,
+    /// `tcp://` and `http://` connect over HTTP, and `https://` (or `tcp://` with `DOCKER_TLS_VERIFY` set) connects
+    /// over TLS using the certificates in `DOCKER_CERT_PATH`. TLS connections require the `remote` feature.
+    /// Falls back to the platform's default local socket when `DOCKER_HOST` is unset.
+    ///
+    /// # Arguments
+    /// * `credentials` - Docker registry credentials for authenticated pulls
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ConnectionError` if the scheme is unsupported or the daemon is unreachable.
+    pub async fn new_from_env(credentials: DockerCredentials) -> AnchorResult<Self> {
+        let docker =
+            Docker::connect_with_defaults().map_err(|err| AnchorError::connection_error(err.to_string()).with_source(err))?;
+
+        Self::from_docker(docker, credentials, None).await
+    }
+
+    /// Creates a new Docker client connected to a remote Docker daemon over TLS.
+    ///
+    /// # Arguments
+    /// * `credentials` - Docker registry credentials for authenticated pulls
+    /// * `host` - Address of the remote daemon (e.g., `tcp://docker.example.com:2376`)
+    /// * `cert_path` - Path to the client certificate (`cert.pem`)
+    /// * `key_path` - Path to the client private key (`key.pem`)
+    /// * `ca_path` - Path to the certificate authority used to verify the daemon (`ca.pem`)
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ConnectionError` if the certificates cannot be loaded or the daemon is unreachable.
+    #[cfg(feature = "remote")]
+    pub async fn new_with_tls<P: AsRef<Path>>(
+        credentials: DockerCredentials,
+        host: &str,
+        cert_path: P,
+        key_path: P,
+        ca_path: P,
+    ) -> AnchorResult<Self> {
+        let docker = Docker::connect_with_ssl(
+            host,
+            key_path.as_ref(),
+            cert_path.as_ref(),
+            ca_path.as_ref(),
+            DEFAULT_TIMEOUT_SECS,
+            API_DEFAULT_VERSION,
+        )
+        .map_err(|err| AnchorError::connection_error(err.to_string()).with_source(err))?;
+
+        Self::from_docker(docker, credentials, None).await
+    }
+
+    /// Creates a new Docker client connected to a remote Docker daemon over plain, unencrypted HTTP.
+    ///
+    /// Intended for development only: traffic, including registry credentials, is sent in the clear.
+    ///
+    /// # Arguments
+    /// * `credentials` - Docker registry credentials for authenticated pulls
+    /// * `host` - Address of the remote daemon (e.g., `tcp://localhost:2375`)
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ConnectionError` if the daemon is unreachable.
+    #[cfg(feature = "remote")]
+    pub async fn new_with_http(credentials: DockerCredentials, host: &str) -> AnchorResult<Self> {
+        let docker = Docker::connect_with_http(host, DEFAULT_TIMEOUT_SECS, API_DEFAULT_VERSION)
+            .map_err(|err| AnchorError::connection_error(err.to_string()).with_source(err))?;
 
-        // Get platform information
+        Self::from_docker(docker, credentials, None).await
+    }
+
+    /// Creates a new Docker client with the given HTTP request timeout.
+    ///
+    /// Useful when long-running operations such as large image pulls exceed the default timeout.
+    ///
+    /// # Arguments
+    /// * `credentials` - Docker registry credentials for authenticated pulls
+    /// * `timeout` - Timeout applied to each request to the Docker daemon
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ConnectionError` if Docker daemon is unreachable.
+    pub async fn new_with_timeout(credentials: DockerCredentials, timeout: Duration) -> AnchorResult<Self> {
+        ClientBuilder::new().credentials(credentials).timeout(timeout).build().await
+    }
+
+    /// Returns a builder for configuring connection options such as the timeout and socket path.
+    #[must_use]
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
+
+    /// Wraps an established Docker connection, retrieving platform information from the daemon.
+    ///
+    /// # Arguments
+    /// * `docker` - Connection to the Docker daemon
+    /// * `credentials` - Docker registry credentials for authenticated pulls
+    /// * `platform_override` - Platform to use instead of the one reported by the daemon
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ConnectionError` if the Docker daemon is unreachable.
+    pub(crate) async fn from_docker(
+        docker: Docker,
+        credentials: DockerCredentials,
+        platform_override: Option<Platform>,
+    ) -> AnchorResult<Self> {
+        // Get platform information, which also confirms the daemon is reachable
         let info = docker.info().await?;
-        let os = info.os_type.as_deref().unwrap_or("unknown");
-        let arch = info.architecture.as_deref().unwrap_or("unknown");
-        let platform = format!("{os}/{arch}");
+        let platform = platform_override.unwrap_or_else(|| {
+            let os = info.os_type.as_deref().unwrap_or("unknown");
+            let arch = info.architecture.as_deref().unwrap_or("unknown");
+            Platform::new(os, arch)
+        });
+        let host_memory = info
+            .mem_total
+            .and_then(|total| u64::try_from(total).ok())
+            .filter(|total| *total > 0);
 
         Ok(Self {
             docker,
             credentials,
             platform,
+            startup_grace_period: Duration::ZERO,
+            startup_log_lines: DEFAULT_STARTUP_LOG_LINES,
+            host_memory,
         })
     }
 
-    /// Returns the platform string (OS/architecture) of the Docker daemon.
+    /// Sets how start failures are detected and reported by `start_container`.
+    pub(crate) const fn with_startup_check(mut self, grace_period: Duration, log_lines: u64) -> Self {
+        self.startup_grace_period = grace_period;
+        self.startup_log_lines = log_lines;
+        self
+    }
+
+    /// Returns the platform (OS/architecture) of the Docker daemon.
     ///
-    /// Format: "linux/amd64", "darwin/arm64", etc.
+    /// Displays as "linux/amd64", "darwin/arm64", etc.
     #[must_use]
-    pub fn platform(&self) -> &str {
+    pub const fn platform(&self) -> &Platform {
         &self.platform
     }
 
+    /// Checks if the `docker` command line tool is installed and on the `PATH`.
+    ///
+    /// Unlike `is_docker_running`, this does not need a client or a running daemon, so it can distinguish
+    /// "Docker is not installed" from "Docker is installed but not running".
+    #[must_use]
+    pub fn is_docker_installed() -> bool {
+        Command::new("docker")
+            .arg("--version")
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .is_ok_and(|status| status.success())
+    }
+
     /// Checks if the Docker daemon is still responsive.
     ///
     /// Useful for health checks and connection validation.
@@ -79,11 +314,71 @@ impl Client {
         self.docker.version().await.is_ok()
     }
 
+    /// Checks if the Docker daemon responds within the given time.
+    ///
+    /// Unlike `is_docker_running`, a daemon that is hung rather than down is reported as not running
+    /// once the timeout elapses, instead of blocking until the request itself times out.
+    ///
+    /// # Arguments
+    /// * `timeout` - Maximum time to wait for the daemon to respond
+    pub async fn is_docker_running_timeout(&self, timeout: Duration) -> bool {
+        matches!(with_timeout(timeout, self.docker.version()).await, Ok(Ok(_)))
+    }
+
+    /// Gets system-wide information about the Docker daemon and its host.
+    ///
+    /// Useful for detecting resource constraints before starting containers and for diagnostic reporting.
+    /// Nothing is cached, so this also works as a liveness check with richer output than `is_docker_running`.
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ConnectionError` if the Docker API call fails.
+    pub async fn get_docker_info(&self) -> AnchorResult<DockerInfo> {
+        Ok(DockerInfo::from(self.docker.info().await?))
+    }
+
+    /// Gets version information about the Docker daemon, including the supported API versions.
+    ///
+    /// Nothing is cached, so this also works as a liveness check with richer output than `is_docker_running`.
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ConnectionError` if the Docker API call fails.
+    pub async fn daemon_version(&self) -> AnchorResult<DaemonVersion> {
+        Ok(DaemonVersion::from(self.docker.version().await?))
+    }
+
+    /// Streams container events (create, start, die, stop, destroy, ...) from the Docker daemon as they happen.
+    ///
+    /// Useful for reacting to containers changing state outside of this client instead of polling.
+    /// The stream only ends if the connection to the daemon is lost.
+    ///
+    /// # Arguments
+    /// * `filter` - Labels, containers and actions to report events for
+    ///
+    /// # Errors
+    /// Each item is an `AnchorError::ConnectionError` if the event stream fails.
+    pub fn events(&self, filter: &EventFilter) -> impl Stream<Item = AnchorResult<DockerEvent>> + use<> {
+        let options = EventsOptionsBuilder::default().filters(&filter.to_docker_filters()).build();
+        self.docker
+            .events(Some(options))
+            .map(|event| event.map(DockerEvent::from).map_err(AnchorError::from))
+    }
+
+    /// Gets the disk space used by images, containers and volumes, equivalent to `docker system df`.
+    ///
+    /// Entries not in use are marked as reclaimable, so cleanup tools can report how much space pruning would free.
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ConnectionError` if the Docker API call fails.
+    pub async fn disk_usage(&self) -> AnchorResult<DiskUsageReport> {
+        Ok(DiskUsageReport::from(self.docker.df(None).await?))
+    }
+
     /// Gets the status of a Docker resource, which can be either an image or a container.
     ///
     /// Returns `ResourceStatus::Missing` if the image is missing,
-    /// `ResourceStatus::Available` if the image is available but the container is not running,
-    /// `ResourceStatus::Built` if the container exists but is not running,
+    /// `ResourceStatus::Downloaded` if the image is available but the container does not exist,
+    /// `ResourceStatus::Built` if the container exists but has never been started,
+    /// `ResourceStatus::Exited`, `Paused`, `Restarting`, `Removing` or `Dead` if the container exists in that state,
     /// and `ResourceStatus::Running` if the container is currently running.
     ///
     /// # Arguments
@@ -92,6 +387,14 @@ impl Client {
     ///
     /// # Errors
     /// Returns `AnchorError` if the image or container list cannot be retrieved.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(image = image_reference.as_ref(), container = container_name_or_id.as_ref())
+        )
+    )]
     pub async fn get_resource_status<S: AsRef<str>, T: AsRef<str>>(
         &self,
         image_reference: S,
@@ -113,7 +416,7 @@ impl Client {
 
     /// Gets the status of a Docker image.
     ///
-    /// Returns `ResourceStatus::Available` if the image is present locally,
+    /// Returns `ResourceStatus::Downloaded` if the image is present locally,
     /// or `ResourceStatus::Missing` if it needs to be downloaded.
     ///
     /// # Arguments
@@ -122,7 +425,7 @@ impl Client {
     /// # Errors
     /// Returns `AnchorError` if the image list cannot be retrieved.
     async fn get_image_status<S: AsRef<str>>(&self, image_reference: S) -> AnchorResult<ResourceStatus> {
-        let is_available = self.is_image_downloaded(image_reference).await?;
+        let is_available = self.image_exists(image_reference).await?;
 
         if is_available {
             Ok(ResourceStatus::Downloaded)
@@ -135,10 +438,14 @@ impl Client {
     ///
     /// Returns the appropriate `ResourceStatus` based on the container's current state:
     /// - `ResourceStatus::Missing` if the container doesn't exist
-    /// - `ResourceStatus::Built` if the container exists but is not running
+    /// - `ResourceStatus::Built` if the container has been created but never started
+    /// - `ResourceStatus::Exited` if the container ran and then stopped, carrying its exit code
+    /// - `ResourceStatus::Paused`, `ResourceStatus::Restarting`, `ResourceStatus::Removing` or `ResourceStatus::Dead`
+    ///   for those Docker states
     /// - `ResourceStatus::Running` if the container is running
     ///
-    /// This is a lightweight check that doesn't collect detailed metrics.
+    /// This is a lightweight check that doesn't collect detailed metrics; only exited containers
+    /// require an additional inspection call to retrieve their exit code.
     /// Use `get_container_metrics()` separately if you need detailed runtime information.
     ///
     /// # Arguments
@@ -148,45 +455,112 @@ impl Client {
     /// Returns `AnchorError` if the container list cannot be retrieved.
     async fn get_container_status<S: AsRef<str>>(&self, container_name_or_id: S) -> AnchorResult<ResourceStatus> {
         let container_ref = container_name_or_id.as_ref();
-        let containers = self.list_containers().await?;
-
-        // Find the container by name or ID
-        let container = containers.iter().find(|c| {
-            // Check by ID (full or short)
-            if let Some(id) = &c.id {
-                if id == container_ref || id.starts_with(container_ref) {
-                    return true;
-                }
-            }
+        let Some(container) = self.find_container(container_ref).await? else {
+            return Ok(ResourceStatus::Missing);
+        };
 
-            // Check by name
-            if let Some(names) = &c.names {
-                for name in names {
-                    // Docker names start with '/', so we need to handle both formats
-                    let clean_name = name.strip_prefix('/').unwrap_or(name);
-                    if clean_name == container_ref || name == container_ref {
-                        return true;
+        match container.state {
+            Some(ContainerSummaryStateEnum::RUNNING) => Ok(ResourceStatus::Running),
+            Some(ContainerSummaryStateEnum::PAUSED) => Ok(ResourceStatus::Paused),
+            Some(ContainerSummaryStateEnum::RESTARTING) => Ok(ResourceStatus::Restarting),
+            Some(ContainerSummaryStateEnum::REMOVING) => Ok(ResourceStatus::Removing),
+            Some(ContainerSummaryStateEnum::DEAD) => Ok(ResourceStatus::Dead),
+            Some(ContainerSummaryStateEnum::EXITED) => {
+                // The summary only carries a human-readable status, so inspect for the exit code
+                let inspect = match self
+                    .docker
+                    .inspect_container(container_ref, None::<InspectContainerOptions>)
+                    .await
+                {
+                    Ok(inspect) => inspect,
+                    // An `auto_remove` container can be removed between the listing and the inspect
+                    Err(BollardError::DockerResponseServerError { status_code: 404, .. }) => {
+                        return Ok(ResourceStatus::Missing);
                     }
-                }
+                    Err(err) => {
+                        return Err(
+                            AnchorError::container_error(container_ref, format!("Failed to inspect container: {err}"))
+                                .with_docker_source(err),
+                        );
+                    }
+                };
+                let code = inspect.state.and_then(|state| state.exit_code).unwrap_or(0);
+                Ok(ResourceStatus::Exited { code })
             }
+            // Container exists but has not been started
+            Some(ContainerSummaryStateEnum::CREATED | ContainerSummaryStateEnum::EMPTY) | None => Ok(ResourceStatus::Built),
+        }
+    }
+
+    /// Checks if a Docker container exists, whatever its state.
+    ///
+    /// Matches the container by exact name (with or without Docker's leading `/`), by full ID, or
+    /// by an unambiguous ID prefix.
+    ///
+    /// # Arguments
+    /// * `container_name_or_id` - Container name or ID to check
+    ///
+    /// # Errors
+    /// Returns `AnchorError` if the container list cannot be retrieved.
+    pub async fn container_exists<S: AsRef<str>>(&self, container_name_or_id: S) -> AnchorResult<bool> {
+        Ok(self.find_container(container_name_or_id.as_ref()).await?.is_some())
+    }
+
+    /// Finds a Docker container by name or ID.
+    ///
+    /// Exact name and ID matches take priority, so a container named like the start of another
+    /// container's ID is never mistaken for it. An ID prefix only matches if it is hexadecimal and
+    /// identifies exactly one container.
+    ///
+    /// # Errors
+    /// Returns `AnchorError` if the container list cannot be retrieved.
+    async fn find_container(&self, container_ref: &str) -> AnchorResult<Option<ContainerSummary>> {
+        let containers = self.list_containers().await?;
 
-            false
+        let exact_match = containers.iter().position(|container| {
+            container.id.as_deref() == Some(container_ref)
+                || container.names.iter().flatten().any(|name| {
+                    // Docker names start with '/', so we need to handle both formats
+                    name == container_ref || name.strip_prefix('/') == Some(container_ref)
+                })
         });
+        if let Some(index) = exact_match {
+            return Ok(containers.into_iter().nth(index));
+        }
 
-        container.map_or(Ok(ResourceStatus::Missing), |container| {
-            let state = container
-                .state
-                .as_ref()
-                .map_or_else(|| "unknown".to_string(), ToString::to_string);
+        if container_ref.is_empty() || !container_ref.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Ok(None);
+        }
+        let mut prefix_matches = containers
+            .into_iter()
+            .filter(|container| container.id.as_ref().is_some_and(|id| id.starts_with(container_ref)));
+        let first = prefix_matches.next();
+        Ok(if prefix_matches.next().is_none() { first } else { None })
+    }
 
-            if state == "running" {
-                // Container is running
-                Ok(ResourceStatus::Running)
-            } else {
-                // Container exists but is not running
-                Ok(ResourceStatus::Built)
-            }
-        })
+    /// Gets the details of a container, such as its state, ports, mounts, environment and labels.
+    ///
+    /// # Arguments
+    /// * `container_name_or_id` - Container name or ID to inspect
+    ///
+    /// # Errors
+    /// Returns `AnchorError::NotFound` if the container doesn't exist.
+    /// Returns `AnchorError::ContainerError` if the container cannot be inspected.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(container = container_name_or_id.as_ref()))
+    )]
+    pub async fn inspect_container<S: AsRef<str>>(&self, container_name_or_id: S) -> AnchorResult<ContainerInspect> {
+        let container_ref = container_name_or_id.as_ref();
+        let inspect = self
+            .docker
+            .inspect_container(container_ref, None::<InspectContainerOptions>)
+            .await
+            .map_err(|err| {
+                AnchorError::container_error(container_ref, format!("Failed to inspect container: {err}"))
+                    .with_docker_source(err)
+            })?;
+        Ok(ContainerInspect::from(inspect))
     }
 
     /// Gets detailed runtime metrics for a container.
@@ -198,8 +572,13 @@ impl Client {
     /// * `container_name_or_id` - Container name or ID to get metrics for
     ///
     /// # Errors
-    /// Returns `AnchorError::ContainerError` if the container doesn't exist, isn't running,
+    /// Returns `AnchorError::NotFound` if the container doesn't exist.
+    /// Returns `AnchorError::ContainerError` if the container isn't running,
     /// or if metrics cannot be retrieved.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(container = container_name_or_id.as_ref()))
+    )]
     pub async fn get_container_metrics<S: AsRef<str>>(&self, container_name_or_id: S) -> AnchorResult<ContainerMetrics> {
         let container_ref = container_name_or_id.as_ref();
 
@@ -208,80 +587,36 @@ impl Client {
             .docker
             .inspect_container(container_ref, None::<InspectContainerOptions>)
             .await
-            .map_err(|err| AnchorError::container_error(container_ref, format!("Failed to inspect container: {err}")))?;
+            .map_err(|err| {
+                AnchorError::container_error(container_ref, format!("Failed to inspect container: {err}"))
+                    .with_docker_source(err)
+            })?;
 
         // Get container stats (single shot, not streaming)
-        let stats = self
-            .docker
-            .stats(
-                container_ref,
-                Some(
-                    bollard::query_parameters::StatsOptionsBuilder::default()
-                        .stream(false)
-                        .build(),
-                ),
-            )
-            .collect::<Vec<_>>()
-            .await;
+        let stats = self.get_raw_stats(container_ref).await;
 
         let mut metrics = ContainerMetrics::new();
 
-        // Calculate uptime from container start time
+        // Number of times the restart policy has restarted the container
+        metrics.restart_count = inspect.restart_count.and_then(|count| u32::try_from(count).ok()).unwrap_or(0);
+
+        metrics.created_at = inspect.created.as_deref().and_then(parse_docker_time);
+
         if let Some(state) = inspect.state {
+            // Calculate uptime from container start time
             if let Some(started_at) = state.started_at {
-                // Parse the ISO 8601 timestamp from Docker
-                match DateTime::parse_from_rfc3339(&started_at) {
-                    Ok(start_time) => {
-                        let start_timestamp = start_time.timestamp() as u64;
-
-                        // Get current time
-                        if let Ok(current_time) = SystemTime::now().duration_since(UNIX_EPOCH) {
-                            let current_timestamp = current_time.as_secs();
-
-                            // Calculate uptime
-                            if current_timestamp >= start_timestamp {
-                                metrics.uptime = Duration::from_secs(current_timestamp - start_timestamp);
-                            } else {
-                                // Handle edge case where start time is in the future (clock skew)
-                                metrics.uptime = Duration::from_secs(0);
-                            }
-                        } else {
-                            // Fallback if system time is unavailable
-                            metrics.uptime = Duration::from_secs(0);
-                        }
-                    }
-                    Err(_) => {
-                        // If we can't parse the timestamp, try alternative parsing methods
-                        // Docker sometimes uses slightly different formats
-                        match started_at.parse::<DateTime<Utc>>() {
-                            Ok(start_time) => {
-                                let start_timestamp = start_time.timestamp() as u64;
-
-                                if let Ok(current_time) = SystemTime::now().duration_since(UNIX_EPOCH) {
-                                    let current_timestamp = current_time.as_secs();
-
-                                    if current_timestamp >= start_timestamp {
-                                        metrics.uptime = Duration::from_secs(current_timestamp - start_timestamp);
-                                    } else {
-                                        metrics.uptime = Duration::from_secs(0);
-                                    }
-                                } else {
-                                    metrics.uptime = Duration::from_secs(0);
-                                }
-                            }
-                            Err(err) => {
-                                // Log the parsing error for debugging
-                                eprintln!("Failed to parse container start time '{started_at}': {err}");
-                                metrics.uptime = Duration::from_secs(0);
-                            }
-                        }
-                    }
-                }
+                metrics.uptime = uptime_since(&started_at);
             }
 
+            // Time the container last exited, if it has ever stopped
+            metrics.finished_at = state.finished_at.as_deref().and_then(parse_docker_time);
+
             // Get exit code
             metrics.last_exit_code = state.exit_code;
 
+            // Distinguish an out-of-memory kill from other exits that share exit code 137
+            metrics.oom_killed = state.oom_killed.unwrap_or(false);
+
             // Get health status
             if let Some(health) = state.health {
                 metrics.health_status =
@@ -300,62 +635,174 @@ impl Client {
         }
 
         // Extract metrics from stats if available
-        if let Some(Ok(stat)) = stats.first() {
-            // Memory metrics
-            if let Some(memory) = &stat.memory_stats {
-                metrics.memory_usage = memory.usage.unwrap_or(0);
-                metrics.memory_limit = memory.limit;
-                metrics.calculate_memory_percentage();
-            }
-
-            // CPU metrics
-            if let Some(cpu) = &stat.cpu_stats {
-                if let Some(precpu) = &stat.precpu_stats {
-                    if let (Some(cpu_usage), Some(precpu_usage)) = (&cpu.cpu_usage, &precpu.cpu_usage) {
-                        if let (Some(total_usage), Some(prev_total_usage)) = (cpu_usage.total_usage, precpu_usage.total_usage) {
-                            let cpu_delta = total_usage.saturating_sub(prev_total_usage);
-                            let system_delta = cpu
-                                .system_cpu_usage
-                                .unwrap_or(0)
-                                .saturating_sub(precpu.system_cpu_usage.unwrap_or(0));
-
-                            if system_delta > 0 {
-                                let cpu_count = f64::from(cpu.online_cpus.unwrap_or(1));
-                                metrics.cpu_percentage = (cpu_delta as f64 / system_delta as f64) * cpu_count * 100.0;
-                            }
-                        }
-                    }
-                }
-            }
+        if let Ok(stat) = stats {
+            apply_stats(&mut metrics, &stat, self.host_memory);
+        }
 
-            // Network metrics
-            if let Some(networks) = &stat.networks {
-                metrics.network_rx_bytes = networks.rx_bytes.unwrap_or(0);
-                metrics.network_tx_bytes = networks.tx_bytes.unwrap_or(0);
-            }
+        Ok(metrics)
+    }
 
-            // Block I/O metrics
-            if let Some(blkio) = &stat.blkio_stats {
-                if let Some(io_service_bytes) = &blkio.io_service_bytes_recursive {
-                    for entry in io_service_bytes {
-                        match entry.op.as_deref() {
-                            Some("read" | "Read") => metrics.block_read_bytes += entry.value.unwrap_or(0),
-                            Some("write" | "Write") => metrics.block_write_bytes += entry.value.unwrap_or(0),
-                            _ => {}
-                        }
-                    }
+    /// Waits until a container's health check reports it as healthy.
+    ///
+    /// Polls `get_container_metrics` every `poll_interval` until the container is healthy, it is reported
+    /// unhealthy, or `timeout` has elapsed.
+    ///
+    /// # Arguments
+    /// * `container_name_or_id` - Container name or ID to wait for
+    /// * `timeout` - Maximum time to wait for the container to become healthy
+    /// * `poll_interval` - Time to wait between health checks
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ContainerError` if the container becomes unhealthy, has no health check configured,
+    /// or cannot be inspected.
+    /// Returns `AnchorError::TimeoutError` if the container is not healthy within the timeout.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(container = container_name_or_id.as_ref()))
+    )]
+    pub async fn wait_until_healthy<S: AsRef<str>>(
+        &self,
+        container_name_or_id: S,
+        timeout: Duration,
+        poll_interval: Duration,
+    ) -> AnchorResult<()> {
+        let container_ref = container_name_or_id.as_ref();
+        let deadline = Instant::now() + timeout;
+        loop {
+            match self.get_container_metrics(container_ref).await?.health_status {
+                Some(HealthStatus::Healthy) => return Ok(()),
+                Some(HealthStatus::Unhealthy) => {
+                    return Err(AnchorError::container_error(container_ref, "Container became unhealthy"));
+                }
+                Some(HealthStatus::None) | None => {
+                    return Err(AnchorError::container_error(
+                        container_ref,
+                        "Cannot wait for health: container has no health check configured",
+                    ));
                 }
+                Some(HealthStatus::Starting) => {}
             }
 
-            // Process count (PIDs)
-            if let Some(pids) = &stat.pids_stats {
-                metrics.process_count = pids.current.unwrap_or(0) as u32;
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(AnchorError::TimeoutError(format!(
+                    "Container '{container_ref}' did not become healthy within {}s",
+                    timeout.as_secs()
+                )));
             }
+            sleep(poll_interval.min(remaining)).await;
         }
+    }
+
+    /// Gets detailed runtime metrics for every running container.
+    ///
+    /// Metrics are collected concurrently, with at most a handful of containers queried at once.
+    /// Containers whose metrics cannot be retrieved (e.g. because they stopped mid-collection)
+    /// are skipped rather than failing the whole call.
+    ///
+    /// # Returns
+    /// A map from container name to its metrics.
+    ///
+    /// # Errors
+    /// Returns `AnchorError` if the container list cannot be retrieved.
+    pub async fn get_all_metrics(&self) -> AnchorResult<HashMap<String, ContainerMetrics>> {
+        let names: Vec<String> = self
+            .list_containers()
+            .await?
+            .into_iter()
+            .filter(|container| container.state == Some(ContainerSummaryStateEnum::RUNNING))
+            .filter_map(container_name)
+            .collect();
+
+        let metrics = stream::iter(names)
+            .map(|name| async move {
+                let metrics = self.get_container_metrics(&name).await;
+                (name, metrics)
+            })
+            .buffer_unordered(MAX_CONCURRENT_METRICS)
+            .filter_map(|(name, metrics)| async move { metrics.ok().map(|metrics| (name, metrics)) })
+            .collect()
+            .await;
 
         Ok(metrics)
     }
 
+    /// Lists the processes running inside a container.
+    ///
+    /// Equivalent to `docker top`. The columns reported depend on the daemon's platform, so
+    /// unrecognised columns are kept in each `ProcessInfo::columns`.
+    ///
+    /// # Arguments
+    /// * `container_name_or_id` - Container name or ID to list processes for
+    ///
+    /// # Errors
+    /// Returns `AnchorError::NotFound` if the container doesn't exist.
+    /// Returns `AnchorError::ContainerError` if the container isn't running.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(container = container_name_or_id.as_ref()))
+    )]
+    pub async fn container_processes<S: AsRef<str>>(&self, container_name_or_id: S) -> AnchorResult<Vec<ProcessInfo>> {
+        let container_ref = container_name_or_id.as_ref();
+        let top = self
+            .docker
+            .top_processes(container_ref, None::<TopOptions>)
+            .await
+            .map_err(|err| {
+                AnchorError::container_error(container_ref, format!("Failed to list container processes: {err}"))
+                    .with_docker_source(err)
+            })?;
+
+        let titles = top.titles.unwrap_or_default();
+        Ok(top
+            .processes
+            .unwrap_or_default()
+            .iter()
+            .map(|row| ProcessInfo::from_row(&titles, row))
+            .collect())
+    }
+
+    /// Gets a single raw statistics sample for a container.
+    ///
+    /// Returns the daemon's counters as-is, without the inspection call and derived calculations
+    /// performed by `get_container_metrics()`.
+    ///
+    /// # Arguments
+    /// * `container_name_or_id` - Container name or ID to get stats for
+    ///
+    /// # Errors
+    /// Returns `AnchorError::NotFound` if the container doesn't exist.
+    /// Returns `AnchorError::ContainerError` if stats cannot be retrieved.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(container = container_name_or_id.as_ref()))
+    )]
+    pub async fn get_container_stats_once<S: AsRef<str>>(&self, container_name_or_id: S) -> AnchorResult<ContainerStats> {
+        self.get_raw_stats(container_name_or_id).await.map(ContainerStats::from)
+    }
+
+    /// Requests a single, non-streaming stats sample from the Docker daemon.
+    ///
+    /// # Arguments
+    /// * `container_name_or_id` - Container name or ID to get stats for
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ContainerError` if the stats request fails or returns no sample.
+    async fn get_raw_stats<S: AsRef<str>>(&self, container_name_or_id: S) -> AnchorResult<ContainerStatsResponse> {
+        let container_ref = container_name_or_id.as_ref();
+        let options = StatsOptionsBuilder::default().stream(false).build();
+
+        self.docker
+            .stats(container_ref, Some(options))
+            .next()
+            .await
+            .ok_or_else(|| AnchorError::container_error(container_ref, "No stats returned for container"))?
+            .map_err(|err| {
+                AnchorError::container_error(container_ref, format!("Failed to get container stats: {err}"))
+                    .with_docker_source(err)
+            })
+    }
+
     /// Lists all Docker images on the system, including intermediate images.
     ///
     /// # Errors
@@ -365,67 +812,203 @@ impl Client {
         self.docker
             .list_images(Some(options))
             .await
-            .map_err(|err| AnchorError::ConnectionError(err.to_string()))
+            .map_err(|err| AnchorError::connection_error(err.to_string()).with_docker_source(err))
     }
 
-    /// Checks if a specific Docker image is available locally.
+    /// Lists all Docker images on the system as crate-owned summaries.
     ///
-    /// Supports both full registry URIs and short tags for matching.
+    /// # Errors
+    /// Returns `AnchorError::ConnectionError` if the Docker API call fails.
+    pub async fn list_image_info(&self) -> AnchorResult<Vec<ImageInfo>> {
+        Ok(self.list_images().await?.into_iter().map(ImageInfo::from).collect())
+    }
+
+    /// Lists the Docker images matching a filter, with the filtering done by the daemon.
+    ///
+    /// Unlike `list_images`, intermediate images are excluded, so large build hosts can be queried cheaply.
     ///
     /// # Arguments
-    /// * `image_reference` - Full image URI or short name (e.g., "nginx:latest")
+    /// * `filter` - Dangling, label and reference conditions the images must satisfy
     ///
     /// # Errors
-    /// Returns `AnchorError` if the image list cannot be retrieved.
-    async fn is_image_downloaded<S: AsRef<str>>(&self, image_reference: S) -> AnchorResult<bool> {
-        let target_ref = image_reference.as_ref();
-
-        // Extract short tag for comparison
-        let short_tag = target_ref.split('/').next_back().unwrap_or(target_ref);
-
-        for image in self.list_images().await? {
-            for tag in &image.repo_tags {
-                // Check both full URI and short tag
-                if tag == target_ref || tag == short_tag {
-                    return Ok(true);
-                }
-            }
-        }
-
-        Ok(false)
+    /// Returns `AnchorError::ConnectionError` if the Docker API call fails.
+    pub async fn list_images_filtered(&self, filter: &ImageFilter) -> AnchorResult<Vec<ImageInfo>> {
+        let options = ListImagesOptionsBuilder::default()
+            .filters(&filter.to_docker_filters())
+            .build();
+        Ok(self
+            .docker
+            .list_images(Some(options))
+            .await?
+            .into_iter()
+            .map(ImageInfo::from)
+            .collect())
     }
 
-    /// Downloads a Docker image from a registry.
+    /// Gets the on-disk size of a locally available Docker image in bytes.
     ///
-    /// Automatically uses the configured credentials for authenticated registries.
+    /// Returns `None` if the image is not available locally.
     ///
     /// # Arguments
-    /// * `image_reference` - Full image URI to download
+    /// * `image_reference` - Full image URI or short name (e.g., "nginx:latest")
     ///
     /// # Errors
-    /// Returns `AnchorError::ImageError` if the download fails.
-    pub async fn pull_image<S: AsRef<str>>(&self, image_reference: S) -> AnchorResult<()> {
-        let options = CreateImageOptionsBuilder::default()
-            .from_image(image_reference.as_ref())
-            .platform(&self.platform)
-            .build();
+    /// Returns `AnchorError` if the image list cannot be retrieved.
+    pub async fn image_size<S: AsRef<str>>(&self, image_reference: S) -> AnchorResult<Option<u64>> {
+        Ok(self
+            .find_image(image_reference)
+            .await?
+            .map(|image| ImageInfo::from(image).size_bytes))
+    }
+
+    /// Checks if a specific Docker image is available locally.
+    ///
+    /// Supports both full registry URIs and short tags for matching.
+    ///
+    /// # Arguments
+    /// * `image_reference` - Full image URI or short name (e.g., "nginx:latest")
+    ///
+    /// # Errors
+    /// Returns `AnchorError` if the image list cannot be retrieved.
+    pub async fn image_exists<S: AsRef<str>>(&self, image_reference: S) -> AnchorResult<bool> {
+        Ok(self.find_image(image_reference).await?.is_some())
+    }
+
+    /// Finds a locally available Docker image by reference.
+    ///
+    /// Supports both full registry URIs and short tags for matching.
+    ///
+    /// # Arguments
+    /// * `image_reference` - Full image URI or short name (e.g., "nginx:latest")
+    ///
+    /// # Errors
+    /// Returns `AnchorError` if the image list cannot be retrieved.
+    async fn find_image<S: AsRef<str>>(&self, image_reference: S) -> AnchorResult<Option<ImageSummary>> {
+        let target_ref = image_reference.as_ref();
+
+        // Extract short tag for comparison
+        let short_tag = target_ref.split('/').next_back().unwrap_or(target_ref);
+
+        Ok(self.list_images().await?.into_iter().find(|image| {
+            // Check both full URI and short tag
+            image.repo_tags.iter().any(|tag| tag == target_ref || tag == short_tag)
+        }))
+    }
 
-        let mut stream = self.docker.create_image(Some(options), None, Some(self.credentials.clone()));
+    /// Downloads a Docker image from a registry.
+    ///
+    /// Automatically uses the configured credentials for authenticated registries.
+    /// If the client has no credentials, the image is pulled anonymously.
+    /// Use `pull_image_with_progress` to observe the download as it happens.
+    ///
+    /// # Arguments
+    /// * `image_reference` - Full image URI to download
+    ///
+    /// # Returns
+    /// Whether the image was already up to date, and how many layers and bytes were downloaded otherwise
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ImageError` if the download fails.
+    pub async fn pull_image<S: AsRef<str>>(&self, image_reference: S) -> AnchorResult<PullOutcome> {
+        self.pull_image_with_progress(image_reference, |_| {}).await
+    }
+
+    /// Downloads a Docker image from a registry, reporting each progress event to a callback.
+    ///
+    /// The callback is called for every event Docker sends, in order, e.g. to drive a progress bar
+    /// from each layer's `progress_detail`.
+    ///
+    /// # Arguments
+    /// * `image_reference` - Full image URI to download
+    /// * `callback` - Function called with each progress event
+    ///
+    /// # Returns
+    /// Whether the image was already up to date, and how many layers and bytes were downloaded otherwise
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ImageError` if the download fails.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(image = image_reference.as_ref())))]
+    pub async fn pull_image_with_progress<S: AsRef<str>, F: FnMut(PullProgress)>(
+        &self,
+        image_reference: S,
+        mut callback: F,
+    ) -> AnchorResult<PullOutcome> {
+        let options = CreateImageOptionsBuilder::default()
+            .from_image(image_reference.as_ref())
+            .platform(&self.platform.to_string())
+            .build();
+
+        // Omit the auth header entirely rather than sending empty credentials
+        let credentials = (self.credentials != DockerCredentials::default()).then(|| self.credentials.clone());
+        let mut stream = self.docker.create_image(Some(options), None, credentials);
+        let mut outcome = PullOutcome::default();
+        let mut layer_sizes: HashMap<String, u64> = HashMap::new();
         while let Some(result) = stream.next().await {
-            match result {
-                Ok(_) => {
-                    // Image pull step completed successfully, continue
+            let info = result.map_err(|err| {
+                AnchorError::image_error(&image_reference, format!("Failed to pull image: {err}")).with_docker_source(err)
+            })?;
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                image = image_reference.as_ref(),
+                status = info.status.as_deref().unwrap_or_default(),
+                layer = info.id.as_deref().unwrap_or_default(),
+                progress = info.progress.as_deref().unwrap_or_default(),
+                "Pulling image"
+            );
+
+            // Layer sizes are only reported in download progress, so remember each layer's total
+            let progress = PullProgress::from(info);
+            match progress.status.as_str() {
+                "Downloading" if !progress.layer_id.is_empty() => {
+                    if let Some(detail) = progress.progress_detail
+                        && detail.total > 0
+                    {
+                        let _unused = layer_sizes.insert(progress.layer_id.clone(), detail.total);
+                    }
                 }
-                Err(err) => {
-                    return Err(AnchorError::image_error(
-                        image_reference,
-                        format!("Failed to pull image: {err}"),
-                    ));
+                "Pull complete" if !progress.layer_id.is_empty() => {
+                    outcome.layers_downloaded += 1;
+                    outcome.bytes += layer_sizes.get(&progress.layer_id).copied().unwrap_or(0);
                 }
+                status if status.starts_with("Status: Image is up to date") => outcome.already_up_to_date = true,
+                _ => {}
             }
+            callback(progress);
         }
 
-        Ok(())
+        #[cfg(feature = "tracing")]
+        tracing::info!(image = image_reference.as_ref(), %outcome, "Pulled image");
+        Ok(outcome)
+    }
+
+    /// Downloads several Docker images from their registries concurrently.
+    ///
+    /// Each image is pulled as with `pull_image`. A failure to pull one image does not prevent the others
+    /// from being pulled; each image's outcome is reported separately so callers can decide whether to proceed.
+    /// Image references listed more than once are pulled once.
+    ///
+    /// # Arguments
+    /// * `image_references` - Full image URIs to download
+    ///
+    /// # Returns
+    /// A map from image reference to the result of pulling it.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub async fn pull_images_concurrently<S: AsRef<str> + Sync>(
+        &self,
+        image_references: &[S],
+    ) -> HashMap<String, AnchorResult<PullOutcome>> {
+        let mut unique_references: Vec<&str> = image_references.iter().map(AsRef::as_ref).collect();
+        unique_references.sort_unstable();
+        unique_references.dedup();
+        stream::iter(unique_references)
+            .map(|image_reference| async move {
+                let result = self.pull_image(image_reference).await;
+                (image_reference.to_string(), result)
+            })
+            .buffer_unordered(MAX_CONCURRENT_BULK_OPERATIONS)
+            .collect()
+            .await
     }
 
     /// Creates a new Docker container from an image with port mappings, environment variables, and mounts.
@@ -433,11 +1016,12 @@ impl Client {
     /// The container is created but not started. Configures port bindings
     /// to map container ports to host ports, sets environment variables, and
     /// sets up volume and bind mounts.
+    /// Use `build_container_with_spec` to configure further options such as health checks.
     ///
     /// # Arguments
     /// * `image_reference` - Docker image to create container from
-    /// * `container_name` - Name to assign to the new container
-    /// * `port_mappings` - `HashMap` mapping container ports to host ports
+    /// * `container_name` - Name to assign to the new container, or empty to let Docker generate one
+    /// * `port_mappings` - Container ports to publish on the host, e.g. `PortMapping::tcp(80, 8080)`
     /// * `env_vars` - `HashMap` of environment variable key-value pairs
     /// * `mounts` - Array of mount configurations (volumes, bind mounts, etc.)
     ///
@@ -445,93 +1029,131 @@ impl Client {
     /// The container ID of the created container.
     ///
     /// # Errors
-    /// Returns `AnchorError::ContainerError` if creation fails or image doesn't exist.
+    /// Returns `AnchorError::NotFound` if the image doesn't exist.
+    /// Returns `AnchorError::ContainerError` if creation fails.
     pub async fn build_container<S: AsRef<str>, T: AsRef<str>>(
         &self,
         image_reference: S,
         container_name: T,
-        port_mappings: &HashMap<u16, u16>,
+        port_mappings: &[PortMapping],
         env_vars: &HashMap<String, String>,
         mounts: &[MountType],
     ) -> AnchorResult<String> {
-        // Check if image exists first
-        if !self.is_image_downloaded(image_reference.as_ref()).await? {
+        let spec = ContainerSpec {
+            port_mappings: port_mappings.to_vec(),
+            env_vars: env_vars.clone(),
+            mounts: mounts.to_vec(),
+            ..ContainerSpec::default()
+        };
+        self.build_container_with_spec(image_reference, container_name, &spec).await
+    }
+
+    /// Creates a new Docker container from an image using a full `ContainerSpec`.
+    ///
+    /// The container is created but not started. In addition to port bindings, environment
+    /// variables and mounts, any optional settings in the spec (such as a health check) are applied.
+    ///
+    /// # Arguments
+    /// * `image_reference` - Docker image to create container from
+    /// * `container_name` - Name to assign to the new container, or empty to let Docker generate one
+    /// * `spec` - Configuration of the container to create
+    ///
+    /// # Returns
+    /// The container ID of the created container.
+    ///
+    /// # Errors
+    /// Returns `AnchorError::NotFound` if the image doesn't exist.
+    /// Returns `AnchorError::ContainerError` if the container name, image reference or spec is invalid (listing every
+    /// problem found), or if creation fails.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(image = image_reference.as_ref(), container = container_name.as_ref())
+        )
+    )]
+    pub async fn build_container_with_spec<S: AsRef<str>, T: AsRef<str>>(
+        &self,
+        image_reference: S,
+        container_name: T,
+        spec: &ContainerSpec,
+    ) -> AnchorResult<String> {
+        // Report every configuration problem at once rather than one Docker error at a time
+        let mut errors = spec.validation_errors();
+        if !is_valid_image_reference(image_reference.as_ref()) {
+            errors.insert(0, format!("image reference '{}' is not valid", image_reference.as_ref()));
+        }
+        // An empty name lets Docker generate one
+        if !container_name.as_ref().is_empty() && !is_valid_container_name(container_name.as_ref()) {
+            errors.insert(
+                0,
+                format!(
+                    "container name '{}' must match [a-zA-Z0-9][a-zA-Z0-9_.-]+",
+                    container_name.as_ref()
+                ),
+            );
+        }
+        if !errors.is_empty() {
             return Err(AnchorError::container_error(
                 container_name,
-                format!("Cannot build container: image '{}' not found", image_reference.as_ref()),
+                format!("Invalid container configuration: {}", errors.join("; ")),
             ));
         }
 
-        // Configure port bindings
-        let mut exposed_ports = HashMap::new();
-        let mut port_bindings = HashMap::new();
+        #[cfg(feature = "tracing")]
+        for warning in spec.validation_warnings() {
+            tracing::warn!(container = container_name.as_ref(), "{warning}");
+        }
 
-        for (container_port, host_port) in port_mappings {
-            // Add to exposed ports (Docker requires the "/tcp" suffix)
-            #[expect(
-                clippy::zero_sized_map_values,
-                reason = "The seemingly odd choice of a `HashMap::new` type for the map value is a upstream requirement for a `bollard::models::PortBinding`."
-            )]
-            let _unused = exposed_ports.insert(format!("{container_port}/tcp"), HashMap::new());
-
-            // Add to port bindings
-            let _unused = port_bindings.insert(
-                format!("{container_port}/tcp"),
-                Some(vec![PortBinding {
-                    host_port: Some(host_port.to_string()),
-                    ..Default::default()
-                }]),
-            );
+        // Check if image exists first
+        if !self.image_exists(image_reference.as_ref()).await? {
+            return Err(AnchorError::not_found(ResourceKind::Image, image_reference));
         }
 
-        // Configure environment variables
-        let environment: Vec<String> = env_vars.iter().map(|(key, value)| format!("{key}={value}")).collect();
+        // Expose each mapped container port (Docker requires the protocol suffix, e.g. "/tcp")
+        #[expect(
+            clippy::zero_sized_map_values,
+            reason = "The seemingly odd choice of a `HashMap::new` type for the map value is a upstream requirement for a `bollard::models::PortBinding`."
+        )]
+        let exposed_ports: HashMap<String, HashMap<(), ()>> = spec
+            .port_mappings
+            .iter()
+            .map(|mapping| (mapping.container_port_key(), HashMap::new()))
+            .collect();
 
-        // Configure mounts
-        let mount_configs: Vec<Mount> = mounts
+        // Configure environment variables, reading any environment files now so they reflect their current contents
+        let environment: Vec<String> = spec
+            .environment()
+            .await
+            .map_err(|message| {
+                AnchorError::container_error(&container_name, format!("Invalid container configuration: {message}"))
+            })?
             .iter()
-            .map(|mount| Mount {
-                target: Some(mount.target().to_string()),
-                source: mount.source().map(String::from),
-                typ: Some(match mount {
-                    MountType::Bind { .. } => MountTypeEnum::BIND,
-                    MountType::Volume { .. } | MountType::AnonymousVolume { .. } => MountTypeEnum::VOLUME,
-                }),
-                read_only: Some(mount.is_read_only()),
-                consistency: None,
-                bind_options: match mount {
-                    MountType::Bind { .. } => Some(MountBindOptions {
-                        propagation: None,
-                        non_recursive: None,
-                        create_mountpoint: Some(true), // Create the mount point if it doesn't exist
-                        read_only_force_recursive: None,
-                        read_only_non_recursive: None,
-                    }),
-                    _ => None,
-                },
-                volume_options: match mount {
-                    MountType::Volume { .. } | MountType::AnonymousVolume { .. } => Some(MountVolumeOptions {
-                        no_copy: None,
-                        labels: None,
-                        driver_config: None,
-                        subpath: None,
-                    }),
-                    MountType::Bind { .. } => None,
-                },
-                tmpfs_options: None,
-                image_options: None,
-            })
+            .map(|(key, value)| format!("{key}={value}"))
             .collect();
 
         let config = ContainerCreateBody {
             image: Some(image_reference.as_ref().to_string()),
             exposed_ports: Some(exposed_ports),
             env: if environment.is_empty() { None } else { Some(environment) },
-            host_config: Some(HostConfig {
-                port_bindings: Some(port_bindings),
-                mounts: if mount_configs.is_empty() { None } else { Some(mount_configs) },
-                ..Default::default()
-            }),
+            labels: if spec.labels.is_empty() {
+                None
+            } else {
+                Some(spec.labels.clone())
+            },
+            healthcheck: spec.health_check.as_ref().map(HealthConfig::from),
+            cmd: spec.args.clone(),
+            entrypoint: spec.entrypoint.clone(),
+            hostname: spec.hostname.clone(),
+            domainname: spec.domainname.clone(),
+            user: spec.user.clone(),
+            working_dir: spec.working_dir.clone(),
+            stop_signal: spec.stop_signal.as_ref().map(|signal| signal.as_str().to_string()),
+            stop_timeout: spec
+                .stop_grace_period
+                .map(|grace_period| i64::try_from(grace_period.as_secs()).unwrap_or(i64::MAX)),
+            host_config: Some(host_config(spec)),
             ..Default::default()
         };
 
@@ -547,6 +1169,7 @@ impl Client {
                     err
                 ),
             )
+            .with_docker_source(err)
         })?;
 
         Ok(container_info.id)
@@ -561,13 +1184,16 @@ impl Client {
     ///
     /// # Errors
     /// Returns `AnchorError::ImageError` if removal fails.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(image = image_reference.as_ref())))]
     pub async fn remove_image<S: AsRef<str>>(&self, image_reference: S) -> AnchorResult<()> {
         let options = RemoveImageOptionsBuilder::default().force(true).build();
         let _unused = self
             .docker
             .remove_image(image_reference.as_ref(), Some(options), Some(self.credentials.clone()))
             .await
-            .map_err(|err| AnchorError::image_error(image_reference, format!("Failed to remove image: {err}")))?;
+            .map_err(|err| {
+                AnchorError::image_error(image_reference, format!("Failed to remove image: {err}")).with_docker_source(err)
+            })?;
         Ok(())
     }
 
@@ -580,49 +1206,837 @@ impl Client {
         Ok(self.docker.list_containers(Some(options)).await?)
     }
 
+    /// Lists all containers (running and stopped) that match a filter.
+    ///
+    /// # Arguments
+    /// * `filter` - Labels and name prefix the containers must match
+    ///
+    /// # Errors
+    /// Returns `AnchorError` if the container list cannot be retrieved.
+    pub async fn list_containers_filtered(&self, filter: &ContainerFilter) -> AnchorResult<Vec<ContainerSummary>> {
+        let label_filters = filter.label_filters();
+        let mut filters = HashMap::new();
+        if !label_filters.is_empty() {
+            let _unused = filters.insert("label", label_filters.iter().map(String::as_str).collect::<Vec<_>>());
+        }
+        let options = ListContainersOptionsBuilder::default().all(true).filters(&filters).build();
+
+        Ok(self
+            .docker
+            .list_containers(Some(options))
+            .await?
+            .into_iter()
+            .filter(|container| filter.matches(container))
+            .collect())
+    }
+
+    /// Starts every stopped container matching a filter, and unpauses every paused one.
+    ///
+    /// Containers that have been created or have exited are started, and paused containers are unpaused.
+    /// Containers that are already running or restarting, or that are dead or being removed, are left alone.
+    ///
+    /// Containers are started concurrently. A failure to start one container does not prevent the
+    /// others from being started; each container's outcome is reported separately.
+    ///
+    /// # Arguments
+    /// * `filter` - Labels and name prefix the containers must match
+    ///
+    /// # Returns
+    /// A map from container name to the result of starting or unpausing it.
+    ///
+    /// # Errors
+    /// Returns `AnchorError` if the container list cannot be retrieved.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub async fn start_all(&self, filter: &ContainerFilter) -> AnchorResult<HashMap<String, AnchorResult<()>>> {
+        let targets: Vec<(String, bool)> = self
+            .list_containers_filtered(filter)
+            .await?
+            .into_iter()
+            .filter_map(|container| {
+                let paused = match container.state {
+                    Some(ContainerSummaryStateEnum::CREATED | ContainerSummaryStateEnum::EXITED) => false,
+                    Some(ContainerSummaryStateEnum::PAUSED) => true,
+                    _ => return None,
+                };
+                container_name(container).map(|name| (name, paused))
+            })
+            .collect();
+        Ok(stream::iter(targets)
+            .map(|(name, paused)| async move {
+                let result = if paused {
+                    self.unpause_container(&name).await
+                } else {
+                    self.start_container(&name).await
+                };
+                (name, result)
+            })
+            .buffer_unordered(MAX_CONCURRENT_BULK_OPERATIONS)
+            .collect()
+            .await)
+    }
+
+    /// Stops every running, restarting or paused container matching a filter.
+    ///
+    /// Containers are stopped concurrently, each as with `stop_container`. A failure to stop one
+    /// container does not prevent the others from being stopped; each container's outcome is
+    /// reported separately.
+    ///
+    /// # Arguments
+    /// * `filter` - Labels and name prefix the containers must match
+    ///
+    /// # Returns
+    /// A map from container name to the result of stopping it.
+    ///
+    /// # Errors
+    /// Returns `AnchorError` if the container list cannot be retrieved.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub async fn stop_all(&self, filter: &ContainerFilter) -> AnchorResult<HashMap<String, AnchorResult<()>>> {
+        let names = self
+            .filtered_container_names(filter, |state| {
+                matches!(
+                    state,
+                    Some(
+                        ContainerSummaryStateEnum::RUNNING
+                            | ContainerSummaryStateEnum::RESTARTING
+                            | ContainerSummaryStateEnum::PAUSED
+                    )
+                )
+            })
+            .await?;
+        Ok(stream::iter(names)
+            .map(|name| async move {
+                let result = self.stop_container(&name).await;
+                (name, result)
+            })
+            .buffer_unordered(MAX_CONCURRENT_BULK_OPERATIONS)
+            .collect()
+            .await)
+    }
+
+    /// Lists the names of containers matching a filter whose state satisfies a predicate.
+    ///
+    /// # Errors
+    /// Returns `AnchorError` if the container list cannot be retrieved.
+    async fn filtered_container_names<F: Fn(Option<ContainerSummaryStateEnum>) -> bool>(
+        &self,
+        filter: &ContainerFilter,
+        state_predicate: F,
+    ) -> AnchorResult<Vec<String>> {
+        Ok(self
+            .list_containers_filtered(filter)
+            .await?
+            .into_iter()
+            .filter(|container| state_predicate(container.state))
+            .filter_map(container_name)
+            .collect())
+    }
+
+    /// Resumes the processes of a paused container.
+    ///
+    /// # Errors
+    /// Returns `AnchorError::NotFound` if the container does not exist.
+    /// Returns `AnchorError::ContainerError` if the container cannot be unpaused.
+    async fn unpause_container(&self, container_ref: &str) -> AnchorResult<()> {
+        self.docker.unpause_container(container_ref).await.map_err(|err| {
+            AnchorError::container_error(container_ref, format!("Failed to unpause container: {err}")).with_docker_source(err)
+        })
+    }
+
     /// Starts an existing Docker container.
     ///
     /// The container must already be created (built) before it can be started.
+    /// If the daemon rejects the start, or the container exits with a non-zero code within the client's
+    /// startup grace period (see `ClientBuilder::startup_check`), the last lines of the container's logs
+    /// are attached to the error. A container that exits with code 0, or is auto-removed, within the grace
+    /// period is treated as started, so one-shot jobs can finish quickly.
     ///
     /// # Arguments
     /// * `container_name_or_id` - Container name or ID to start
     ///
     /// # Errors
-    /// Returns `AnchorError::ContainerError` if the container cannot be started.
+    /// Returns `AnchorError::NotFound` if the container does not exist.
+    /// Returns `AnchorError::ContainerError` if the container cannot be started or fails during the grace period.
+    /// Returns `AnchorError::ConnectionError` if the daemon cannot be reached.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(container = container_name_or_id.as_ref()))
+    )]
     pub async fn start_container<S: AsRef<str>>(&self, container_name_or_id: S) -> AnchorResult<()> {
+        let container_ref = container_name_or_id.as_ref();
         let options = StartContainerOptionsBuilder::default().build();
-        self.docker
-            .start_container(container_name_or_id.as_ref(), Some(options))
+        match self.docker.start_container(container_ref, Some(options)).await {
+            Ok(()) => {}
+            // The container's own output usually explains a rejected start, e.g. a missing entrypoint
+            Err(err @ BollardError::DockerResponseServerError { status_code, .. }) if status_code != 404 => {
+                let logs = self.failure_logs(container_ref, self.startup_log_lines).await;
+                return Err(
+                    AnchorError::container_error(container_ref, format!("Failed to start container: {err}{logs}"))
+                        .with_docker_source(err),
+                );
+            }
+            Err(err @ BollardError::DockerResponseServerError { .. }) => {
+                return Err(
+                    AnchorError::container_error(container_ref, format!("Failed to start container: {err}"))
+                        .with_docker_source(err),
+                );
+            }
+            Err(err) => return Err(AnchorError::from(err)),
+        }
+
+        if self.startup_grace_period.is_zero() {
+            return Ok(());
+        }
+        sleep(self.startup_grace_period).await;
+
+        let state = match self
+            .docker
+            .inspect_container(container_ref, None::<InspectContainerOptions>)
+            .await
+        {
+            Ok(inspect) => inspect.state.unwrap_or_default(),
+            // An `auto_remove` container that already exited is gone, and its exit code with it
+            Err(BollardError::DockerResponseServerError { status_code: 404, .. }) => return Ok(()),
+            Err(err) => return Err(AnchorError::from(err)),
+        };
+        let code = state.exit_code.unwrap_or(0);
+        if state.running.unwrap_or(false) || state.restarting.unwrap_or(false) || code == 0 {
+            return Ok(());
+        }
+
+        let logs = self.failure_logs(container_ref, self.startup_log_lines).await;
+        Err(AnchorError::container_error(
+            container_ref,
+            format!(
+                "Container exited with code {code} within {}ms of starting{logs}",
+                self.startup_grace_period.as_millis()
+            ),
+        ))
+    }
+
+    /// Starts an existing Docker container and waits for it to run to completion.
+    ///
+    /// Intended for one-shot jobs, such as database migrations, that must finish before
+    /// other containers are started. An exit code of 0 is treated as success.
+    ///
+    /// For containers created with `auto_remove`, which Docker deletes as soon as they exit, the wait is
+    /// sent before the container is started and waits for the removal, so the exit code is still captured.
+    /// Logs of such containers may already be gone when a failure is reported. Other containers are waited
+    /// on until they stop running after being started, so a job that exits immediately is not missed.
+    ///
+    /// Once this returns, `get_resource_status` reports the container as `ResourceStatus::Exited` with its
+    /// exit code, or as no longer existing (`ResourceStatus::Downloaded`) if it was created with `auto_remove`.
+    ///
+    /// # Arguments
+    /// * `container_name_or_id` - Container name or ID to run
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ContainerError` if the container cannot be inspected, started or waited on,
+    /// or if it exits with a non-zero code, in which case the error includes the exit code
+    /// and the last few lines of the container's logs.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(container = container_name_or_id.as_ref()))
+    )]
+    pub async fn run_to_completion<S: AsRef<str>>(&self, container_name_or_id: S) -> AnchorResult<()> {
+        let container_ref = container_name_or_id.as_ref();
+        let inspect = self
+            .docker
+            .inspect_container(container_ref, None::<InspectContainerOptions>)
             .await
             .map_err(|err| {
-                AnchorError::container_error(container_name_or_id.as_ref(), format!("Failed to start container: {err}"))
+                AnchorError::container_error(container_ref, format!("Failed to inspect container: {err}"))
+                    .with_docker_source(err)
             })?;
+        let auto_remove = inspect
+            .host_config
+            .and_then(|host_config| host_config.auto_remove)
+            .unwrap_or(false);
 
-        Ok(())
+        let code = if auto_remove {
+            // Poll the wait first so it is in place before the container can exit and be removed
+            let wait = pin!(self.wait_for_exit(container_ref, "removed"));
+            let start = pin!(self.start_container(container_ref));
+            match select(wait, start).await {
+                Either::Left((code, start)) => {
+                    start.await?;
+                    code?
+                }
+                Either::Right((started, wait)) => {
+                    started?;
+                    wait.await?
+                }
+            }
+        } else {
+            // A stopped container stays "not-running", so waiting after the start cannot miss a fast exit
+            self.start_container(container_ref).await?;
+            self.wait_for_exit(container_ref, "not-running").await?
+        };
+        if code == 0 {
+            return Ok(());
+        }
+
+        let logs = self.failure_logs(container_ref, FAILURE_LOG_LINES).await;
+        Err(AnchorError::container_error(
+            container_ref,
+            format!("Container exited with code {code}{logs}"),
+        ))
+    }
+
+    /// Waits for a container to reach the running state.
+    ///
+    /// A container can briefly still report as created just after `start_container` returns, so this
+    /// polls its state until it is running, returning early if it exits during startup.
+    ///
+    /// # Arguments
+    /// * `container` - Container name or ID to wait for
+    /// * `timeout` - Maximum time to wait for the container to be running
+    ///
+    /// # Errors
+    /// Returns `AnchorError::NotFound` if the container does not exist.
+    /// Returns `AnchorError::ContainerError` if the container is dead or exits before running,
+    /// in which case the error includes the exit code and the last few lines of the container's logs.
+    /// Returns `AnchorError::TimeoutError` if the container is not running within the timeout.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(container = container.as_ref())))]
+    pub async fn wait_running<S: AsRef<str>>(&self, container: S, timeout: Duration) -> AnchorResult<()> {
+        let container_ref = container.as_ref();
+        let deadline = Instant::now() + timeout;
+        loop {
+            match self.get_container_status(container_ref).await? {
+                ResourceStatus::Running => return Ok(()),
+                ResourceStatus::Exited { code } => {
+                    let logs = self.failure_logs(container_ref, self.startup_log_lines).await;
+                    return Err(AnchorError::container_error(
+                        container_ref,
+                        format!("Container exited with code {code} before it was running{logs}"),
+                    ));
+                }
+                ResourceStatus::Missing | ResourceStatus::Downloaded => {
+                    return Err(AnchorError::not_found(ResourceKind::Container, container_ref));
+                }
+                ResourceStatus::Dead | ResourceStatus::Removing => {
+                    return Err(AnchorError::container_error(
+                        container_ref,
+                        "Container is dead or being removed",
+                    ));
+                }
+                ResourceStatus::Built | ResourceStatus::Restarting | ResourceStatus::Paused => {}
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(AnchorError::TimeoutError(format!(
+                    "Container '{container_ref}' was not running within {}s",
+                    timeout.as_secs()
+                )));
+            }
+            sleep(WAIT_RUNNING_POLL_INTERVAL.min(remaining)).await;
+        }
+    }
+
+    /// Formats the last lines of a container's logs for appending to an error message.
+    ///
+    /// Returns an empty string if `lines` is zero.
+    async fn failure_logs(&self, container_name_or_id: &str, lines: u64) -> String {
+        if lines == 0 {
+            return String::new();
+        }
+        let logs = self
+            .logs_tail(container_name_or_id, lines)
+            .await
+            .map_or_else(|err| format!("<logs unavailable: {err}>"), |lines| lines.join("\n"));
+        format!("\n--- last {lines} log lines ---\n{logs}\n---")
+    }
+
+    /// Waits for a container to meet a Docker wait condition and returns its exit code.
+    ///
+    /// # Arguments
+    /// * `container_name_or_id` - Container name or ID to wait for
+    /// * `condition` - Docker wait condition, "not-running", "next-exit" or "removed"
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ContainerError` if the wait request fails.
+    async fn wait_for_exit(&self, container_name_or_id: &str, condition: &str) -> AnchorResult<i64> {
+        let options = WaitContainerOptionsBuilder::default().condition(condition).build();
+        let mut stream = self.docker.wait_container(container_name_or_id, Some(options));
+
+        match stream.next().await {
+            Some(Ok(response)) => Ok(response.status_code),
+            // bollard reports non-zero exit codes as errors
+            Some(Err(BollardError::DockerContainerWaitError { code, .. })) => Ok(code),
+            Some(Err(err)) => Err(AnchorError::container_error(
+                container_name_or_id,
+                format!("Failed to wait for container: {err}"),
+            )
+            .with_docker_source(err)),
+            None => Err(AnchorError::container_error(
+                container_name_or_id,
+                "Failed to wait for container: no exit status returned",
+            )),
+        }
+    }
+
+    /// Gets a container's stdout and stderr output, optionally only from a given time onwards.
+    ///
+    /// # Arguments
+    /// * `container_name_or_id` - Container name or ID to get logs for
+    /// * `since` - Only return lines written at or after this time (all lines if `None`)
+    ///
+    /// # Errors
+    /// Returns `AnchorError::NotFound` if the container doesn't exist.
+    /// Returns `AnchorError::ContainerError` if the logs cannot be retrieved.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(container = container_name_or_id.as_ref()))
+    )]
+    pub async fn get_container_logs<S: AsRef<str>>(
+        &self,
+        container_name_or_id: S,
+        since: Option<SystemTime>,
+    ) -> AnchorResult<Vec<LogEntry>> {
+        let container_ref = container_name_or_id.as_ref();
+        let mut options = LogsOptionsBuilder::default().stdout(true).stderr(true).timestamps(true);
+        if let Some(since) = since {
+            // Docker only filters to whole seconds, so finer filtering is done on the timestamps below
+            let seconds = since.duration_since(UNIX_EPOCH).map_or(0, |duration| duration.as_secs());
+            options = options.since(i32::try_from(seconds).unwrap_or(i32::MAX));
+        }
+
+        let mut stream = self.docker.logs(container_ref, Some(options.build()));
+        let mut buffer = LogLineBuffer::default();
+        let mut lines = Vec::new();
+        while let Some(result) = stream.next().await {
+            let chunk = result.map_err(|err| {
+                AnchorError::container_error(container_ref, format!("Failed to read container logs: {err}"))
+                    .with_docker_source(err)
+            })?;
+            lines.extend(buffer.push(chunk));
+        }
+        lines.extend(buffer.finish());
+
+        Ok(lines
+            .into_iter()
+            .map(|(log_stream, line)| LogEntry::from_line(log_stream, &line))
+            .filter(|entry| since.is_none_or(|since| entry.timestamp.is_none_or(|timestamp| timestamp >= since)))
+            .collect())
+    }
+
+    /// Gets a container's stdout and stderr output from its most recent start only.
+    ///
+    /// Useful after a restart, when the output of earlier runs is no longer relevant.
+    /// Returns no entries if the container has never been started.
+    ///
+    /// # Arguments
+    /// * `container_name_or_id` - Container name or ID to get logs for
+    ///
+    /// # Errors
+    /// Returns `AnchorError::NotFound` if the container doesn't exist.
+    /// Returns `AnchorError::ContainerError` if the logs cannot be retrieved.
+    pub async fn get_container_logs_since_restart<S: AsRef<str>>(
+        &self,
+        container_name_or_id: S,
+    ) -> AnchorResult<Vec<LogEntry>> {
+        let container_ref = container_name_or_id.as_ref();
+        let inspect = self
+            .docker
+            .inspect_container(container_ref, None::<InspectContainerOptions>)
+            .await
+            .map_err(|err| {
+                AnchorError::container_error(container_ref, format!("Failed to inspect container: {err}"))
+                    .with_docker_source(err)
+            })?;
+
+        let started_at = inspect
+            .state
+            .and_then(|state| state.started_at)
+            .and_then(|started_at| parse_docker_time(&started_at));
+        let Some(started_at) = started_at else {
+            return Ok(Vec::new());
+        };
+
+        self.get_container_logs(container_ref, Some(started_at)).await
+    }
+
+    /// Gets the last `lines` lines of a container's combined stdout and stderr output.
+    ///
+    /// Lines from both streams are returned in the order they were written, as plain text without
+    /// Docker's stream headers or timestamps, e.g. for an error report. Use `get_container_logs` to
+    /// tell the streams apart or to get each line's timestamp.
+    ///
+    /// # Arguments
+    /// * `container` - Container name or ID to get logs for
+    /// * `lines` - Maximum number of lines to return, counting back from the most recent
+    ///
+    /// # Errors
+    /// Returns `AnchorError::NotFound` if the container doesn't exist.
+    /// Returns `AnchorError::ContainerError` if the logs cannot be retrieved.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(container = container.as_ref())))]
+    pub async fn logs_tail<S: AsRef<str>>(&self, container: S, lines: u64) -> AnchorResult<Vec<String>> {
+        let container_ref = container.as_ref();
+        let options = LogsOptionsBuilder::default()
+            .stdout(true)
+            .stderr(true)
+            .tail(&lines.to_string())
+            .build();
+
+        let mut stream = self.docker.logs(container_ref, Some(options));
+        let mut buffer = LogLineBuffer::default();
+        let mut output = Vec::new();
+        while let Some(result) = stream.next().await {
+            let chunk = result.map_err(|err| {
+                AnchorError::container_error(container_ref, format!("Failed to read container logs: {err}"))
+                    .with_docker_source(err)
+            })?;
+            output.extend(buffer.push(chunk).into_iter().map(|(_, line)| line));
+        }
+        output.extend(buffer.finish().into_iter().map(|(_, line)| line));
+
+        Ok(output)
     }
 
     /// Stops a running Docker container gracefully.
     ///
-    /// Sends SIGTERM and waits up to 10 seconds before forcing termination.
+    /// Sends the container's stop signal (SIGTERM unless set by its `ContainerSpec` or image) and waits
+    /// for the container's stop grace period, 10 seconds by default, after which Docker sends SIGKILL.
+    /// Use `stop_container_with_timeout` to choose the grace period, or `kill_container` to send a
+    /// specific signal immediately instead.
     ///
     /// # Arguments
     /// * `container_name_or_id` - Container name or ID to stop
     ///
     /// # Errors
+    /// Returns `AnchorError::NotFound` if the container does not exist.
     /// Returns `AnchorError::ContainerError` if the container cannot be stopped.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(container = container_name_or_id.as_ref()))
+    )]
     pub async fn stop_container<S: AsRef<str>>(&self, container_name_or_id: S) -> AnchorResult<()> {
-        let options = StopContainerOptionsBuilder::default()
-            .t(10) // 10 seconds timeout
-            .build();
+        // Leave the timeout unset so Docker applies the container's own stop grace period
+        let options = StopContainerOptionsBuilder::default().build();
         self.docker
             .stop_container(container_name_or_id.as_ref(), Some(options))
             .await
             .map_err(|err| {
                 AnchorError::container_error(container_name_or_id.as_ref(), format!("Failed to stop container: {err}"))
+                    .with_docker_source(err)
             })?;
         Ok(())
     }
 
+    /// Stops a running Docker container, waiting the given grace period before it is killed.
+    ///
+    /// Sends the container's stop signal and waits up to `timeout` for it to exit, after which Docker
+    /// sends SIGKILL. A zero timeout kills the container immediately. The timeout is rounded down to
+    /// whole seconds.
+    ///
+    /// # Arguments
+    /// * `container_name_or_id` - Container name or ID to stop
+    /// * `timeout` - Grace period between the stop signal and SIGKILL
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ContainerError` if the timeout exceeds Docker's range or the container
+    /// cannot be stopped.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(container = container_name_or_id.as_ref()))
+    )]
+    pub async fn stop_container_with_timeout<S: AsRef<str>>(
+        &self,
+        container_name_or_id: S,
+        timeout: Duration,
+    ) -> AnchorResult<()> {
+        let container_ref = container_name_or_id.as_ref();
+        let seconds = i32::try_from(timeout.as_secs()).map_err(|_err| {
+            AnchorError::container_error(
+                container_ref,
+                format!(
+                    "Stop timeout of {}s exceeds the maximum of {} seconds",
+                    timeout.as_secs(),
+                    i32::MAX
+                ),
+            )
+        })?;
+
+        let options = StopContainerOptionsBuilder::default().t(seconds).build();
+        self.docker
+            .stop_container(container_ref, Some(options))
+            .await
+            .map_err(|err| {
+                AnchorError::container_error(container_ref, format!("Failed to stop container: {err}")).with_docker_source(err)
+            })?;
+        Ok(())
+    }
+
+    /// Sends a signal to a running Docker container.
+    ///
+    /// Unlike `stop_container`, the signal is delivered immediately with no grace period or
+    /// follow-up SIGKILL, so it can also be used for non-terminating signals such as SIGHUP.
+    /// Signals can also be parsed from strings, e.g. `"SIGHUP".parse::<ContainerSignal>()` or `"9".parse()`.
+    ///
+    /// # Arguments
+    /// * `container_name_or_id` - Container name or ID to signal
+    /// * `signal` - Signal to send to the container's main process
+    ///
+    /// # Errors
+    /// Returns `AnchorError::NotFound` if the container does not exist.
+    /// Returns `AnchorError::ContainerError` if the signal is malformed, the container is not running,
+    /// or the signal cannot be delivered.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(container = container_name_or_id.as_ref(), %signal))
+    )]
+    pub async fn kill_container<S: AsRef<str>>(&self, container_name_or_id: S, signal: ContainerSignal) -> AnchorResult<()> {
+        let container_ref = container_name_or_id.as_ref();
+        if !signal.is_valid() {
+            return Err(AnchorError::container_error(
+                container_ref,
+                format!("Cannot send invalid signal '{signal}': expected a signal name or number"),
+            ));
+        }
+
+        let options = KillContainerOptionsBuilder::default().signal(signal.as_str()).build();
+        self.docker
+            .kill_container(container_ref, Some(options))
+            .await
+            .map_err(|err| match err {
+                BollardError::DockerResponseServerError { status_code: 409, .. } => {
+                    AnchorError::container_error(container_ref, format!("Cannot send {signal}: container is not running"))
+                        .with_docker_source(err)
+                }
+                _ => AnchorError::container_error(container_ref, format!("Failed to send {signal} to container: {err}"))
+                    .with_docker_source(err),
+            })?;
+        Ok(())
+    }
+
+    /// Renames an existing Docker container.
+    ///
+    /// Useful for blue/green deployments, where a new container is built under a temporary name
+    /// and swapped into place once verified.
+    ///
+    /// # Arguments
+    /// * `current` - Current container name or ID
+    /// * `new_name` - New name to assign to the container
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ContainerError` if the rename fails, including when `new_name` is not a
+    /// valid container name or is already in use by another container.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(container = current.as_ref(), new_name = new_name.as_ref()))
+    )]
+    pub async fn rename_container<S: AsRef<str>, T: AsRef<str>>(&self, current: S, new_name: T) -> AnchorResult<()> {
+        if !is_valid_container_name(new_name.as_ref()) {
+            return Err(AnchorError::container_error(
+                current.as_ref(),
+                format!(
+                    "Failed to rename container: name '{}' must match [a-zA-Z0-9][a-zA-Z0-9_.-]+",
+                    new_name.as_ref()
+                ),
+            ));
+        }
+
+        let options = RenameContainerOptionsBuilder::default().name(new_name.as_ref()).build();
+        self.docker
+            .rename_container(current.as_ref(), options)
+            .await
+            .map_err(|err| match err {
+                BollardError::DockerResponseServerError { status_code: 409, .. } => AnchorError::container_error(
+                    current.as_ref(),
+                    format!(
+                        "Failed to rename container: name '{}' is already in use by another container",
+                        new_name.as_ref()
+                    ),
+                )
+                .with_docker_source(err),
+                _ => AnchorError::container_error(
+                    current.as_ref(),
+                    format!("Failed to rename container to '{}': {err}", new_name.as_ref()),
+                )
+                .with_docker_source(err),
+            })?;
+        Ok(())
+    }
+
+    /// Copies files into a Docker container by extracting a tar archive at the given path.
+    ///
+    /// The container does not need to be running, so this can seed configuration files or test
+    /// fixtures after `build_container` and before `start_container`.
+    ///
+    /// # Arguments
+    /// * `container` - Container name or ID
+    /// * `dest_path` - Directory inside the container to extract the archive into; must already exist
+    /// * `tar_bytes` - Tar-formatted archive containing the files to copy
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ContainerError` if the container or destination directory does not exist,
+    /// or if the archive cannot be extracted.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(container = container.as_ref(), path = dest_path.as_ref()))
+    )]
+    pub async fn copy_to_container<S: AsRef<str>, P: AsRef<str>>(
+        &self,
+        container: S,
+        dest_path: P,
+        tar_bytes: Vec<u8>,
+    ) -> AnchorResult<()> {
+        let options = UploadToContainerOptionsBuilder::default().path(dest_path.as_ref()).build();
+        self.docker
+            .upload_to_container(container.as_ref(), Some(options), bollard::body_full(tar_bytes.into()))
+            .await
+            .map_err(|err| {
+                AnchorError::container_error(
+                    container.as_ref(),
+                    format!("Failed to copy archive to '{}': {err}", dest_path.as_ref()),
+                )
+                .with_docker_source(err)
+            })
+    }
+
+    /// Copies a file or directory out of a Docker container as a tar archive.
+    ///
+    /// # Arguments
+    /// * `container` - Container name or ID
+    /// * `src_path` - Path of the file or directory inside the container
+    ///
+    /// # Returns
+    /// Tar-formatted archive containing `src_path`
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ContainerError` if the container or source path does not exist.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(container = container.as_ref(), path = src_path.as_ref()))
+    )]
+    pub async fn copy_from_container<S: AsRef<str>, P: AsRef<str>>(&self, container: S, src_path: P) -> AnchorResult<Vec<u8>> {
+        let options = DownloadFromContainerOptionsBuilder::default().path(src_path.as_ref()).build();
+        let mut stream = self.docker.download_from_container(container.as_ref(), Some(options));
+
+        let mut tar_bytes = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(|err| {
+                AnchorError::container_error(
+                    container.as_ref(),
+                    format!("Failed to copy archive from '{}': {err}", src_path.as_ref()),
+                )
+                .with_docker_source(err)
+            })?;
+            tar_bytes.extend_from_slice(&chunk);
+        }
+        Ok(tar_bytes)
+    }
+
+    /// Gets the changes made to a container's filesystem since it was created, equivalent to `docker diff`.
+    ///
+    /// Useful for auditing what setup scripts changed before committing a container as a new image.
+    ///
+    /// # Arguments
+    /// * `container_name_or_id` - Container name or ID to inspect
+    ///
+    /// # Returns
+    /// Every added, deleted or modified path, empty if the filesystem is unchanged
+    ///
+    /// # Errors
+    /// Returns `AnchorError::NotFound` if the container does not exist.
+    /// Returns `AnchorError::ContainerError` if the changes cannot be retrieved.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(container = container_name_or_id.as_ref()))
+    )]
+    pub async fn get_container_diff<S: AsRef<str>>(&self, container_name_or_id: S) -> AnchorResult<Vec<FilesystemChange>> {
+        let container_ref = container_name_or_id.as_ref();
+        let changes = self.docker.container_changes(container_ref).await.map_err(|err| {
+            AnchorError::container_error(container_ref, format!("Failed to get filesystem changes: {err}"))
+                .with_docker_source(err)
+        })?;
+        Ok(changes.unwrap_or_default().into_iter().map(FilesystemChange::from).collect())
+    }
+
+    /// Creates a named Docker volume.
+    ///
+    /// Creating a volume that already exists with the same driver returns the existing volume.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the volume
+    /// * `driver` - Volume driver to use (Docker's default, `local`, if `None`)
+    /// * `labels` - Labels to attach to the volume
+    ///
+    /// # Returns
+    /// Details of the created volume
+    ///
+    /// # Errors
+    /// Returns `AnchorError::VolumeError` if the volume cannot be created.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(volume = name.as_ref())))]
+    pub async fn create_volume<S: AsRef<str>>(
+        &self,
+        name: S,
+        driver: Option<&str>,
+        labels: &HashMap<String, String>,
+    ) -> AnchorResult<VolumeInfo> {
+        let config = VolumeCreateOptions {
+            name: Some(name.as_ref().to_string()),
+            driver: driver.map(String::from),
+            labels: if labels.is_empty() { None } else { Some(labels.clone()) },
+            ..Default::default()
+        };
+        let volume = self.docker.create_volume(config).await.map_err(|err| {
+            AnchorError::volume_error(name.as_ref(), format!("Failed to create volume: {err}")).with_docker_source(err)
+        })?;
+        Ok(VolumeInfo::from(volume))
+    }
+
+    /// Lists all Docker volumes, including those created implicitly by mounts.
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ConnectionError` if the Docker API call fails.
+    pub async fn list_volumes(&self) -> AnchorResult<Vec<VolumeInfo>> {
+        let response = self.docker.list_volumes(None::<ListVolumesOptions>).await?;
+        Ok(response
+            .volumes
+            .unwrap_or_default()
+            .into_iter()
+            .map(VolumeInfo::from)
+            .collect())
+    }
+
+    /// Gets the details of a named Docker volume.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the volume
+    ///
+    /// # Errors
+    /// Returns `AnchorError::NotFound` if the volume does not exist.
+    /// Returns `AnchorError::VolumeError` if the volume cannot be inspected.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(volume = name.as_ref())))]
+    pub async fn inspect_volume<S: AsRef<str>>(&self, name: S) -> AnchorResult<VolumeInfo> {
+        let volume = self.docker.inspect_volume(name.as_ref()).await.map_err(|err| {
+            AnchorError::volume_error(name.as_ref(), format!("Failed to inspect volume: {err}")).with_docker_source(err)
+        })?;
+        Ok(VolumeInfo::from(volume))
+    }
+
+    /// Removes a named Docker volume.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the volume
+    /// * `force` - Whether to remove the volume even if it is in use by a container
+    ///
+    /// # Errors
+    /// Returns `AnchorError::NotFound` if the volume does not exist.
+    /// Returns `AnchorError::VolumeError` if the volume is in use (without `force`) or cannot be removed.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(volume = name.as_ref())))]
+    pub async fn remove_volume<S: AsRef<str>>(&self, name: S, force: bool) -> AnchorResult<()> {
+        #[expect(
+            deprecated,
+            reason = "bollard 0.19.0's remove_volume does not accept the generated query parameters"
+        )]
+        let options = RemoveVolumeOptions { force };
+        self.docker.remove_volume(name.as_ref(), Some(options)).await.map_err(|err| {
+            AnchorError::volume_error(name.as_ref(), format!("Failed to remove volume: {err}")).with_docker_source(err)
+        })
+    }
+
     /// Forcefully removes a Docker container.
     ///
     /// Removes the container even if it's currently running.
@@ -631,7 +2045,12 @@ impl Client {
     /// * `container_name_or_id` - Container name or ID to remove
     ///
     /// # Errors
+    /// Returns `AnchorError::NotFound` if the container does not exist.
     /// Returns `AnchorError::ContainerError` if removal fails.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(container = container_name_or_id.as_ref()))
+    )]
     pub async fn remove_container<S: AsRef<str>>(&self, container_name_or_id: S) -> AnchorResult<()> {
         let options = RemoveContainerOptionsBuilder::default().force(true).build();
         self.docker
@@ -639,7 +2058,234 @@ impl Client {
             .await
             .map_err(|err| {
                 AnchorError::container_error(container_name_or_id.as_ref(), format!("Failed to remove container: {err}"))
+                    .with_docker_source(err)
             })?;
         Ok(())
     }
 }
+
+/// Calculates how long a container has been up from its Docker `started_at` timestamp.
+///
+/// Returns a zero duration if the timestamp cannot be parsed or lies in the future (clock skew).
+fn uptime_since(started_at: &str) -> Duration {
+    // Parse the ISO 8601 timestamp from Docker, which sometimes uses slightly different formats
+    let start_timestamp = match DateTime::parse_from_rfc3339(started_at) {
+        Ok(start_time) => start_time.timestamp(),
+        Err(_) => match started_at.parse::<DateTime<Utc>>() {
+            Ok(start_time) => start_time.timestamp(),
+            #[cfg_attr(
+                not(feature = "tracing"),
+                expect(unused_variables, reason = "The parse error is only reported when tracing is enabled")
+            )]
+            Err(err) => {
+                // Log the parsing error for debugging
+                #[cfg(feature = "tracing")]
+                tracing::warn!(started_at, %err, "Failed to parse container start time");
+                return Duration::from_secs(0);
+            }
+        },
+    } as u64;
+
+    // Fallback to zero if system time is unavailable
+    let Ok(current_time) = SystemTime::now().duration_since(UNIX_EPOCH) else {
+        return Duration::from_secs(0);
+    };
+
+    Duration::from_secs(current_time.as_secs().saturating_sub(start_timestamp))
+}
+
+/// Copies the memory, CPU, network, block I/O and process figures from a stats sample into the metrics.
+///
+/// Docker reports the host's total memory as the limit of unlimited containers, so a limit at or above
+/// `host_memory` is treated as no limit and the percentage is flagged as host-relative.
+fn apply_stats(metrics: &mut ContainerMetrics, stat: &ContainerStatsResponse, host_memory: Option<u64>) {
+    // Memory metrics
+    if let Some(memory) = &stat.memory_stats {
+        metrics.memory_usage = memory.usage.unwrap_or(0);
+        metrics.memory_limit = memory.limit.filter(|limit| host_memory.is_none_or(|total| *limit < total));
+        metrics.calculate_memory_percentage_or_host(host_memory);
+    }
+
+    // CPU metrics
+    if let (Some(cpu), Some(precpu)) = (&stat.cpu_stats, &stat.precpu_stats)
+        && let (Some(cpu_usage), Some(precpu_usage)) = (&cpu.cpu_usage, &precpu.cpu_usage)
+        && let (Some(total_usage), Some(prev_total_usage)) = (cpu_usage.total_usage, precpu_usage.total_usage)
+    {
+        let cpu_delta = total_usage.saturating_sub(prev_total_usage);
+        let system_delta = cpu
+            .system_cpu_usage
+            .unwrap_or(0)
+            .saturating_sub(precpu.system_cpu_usage.unwrap_or(0));
+
+        if system_delta > 0 {
+            let cpu_count = f64::from(cpu.online_cpus.unwrap_or(1));
+            metrics.cpu_percentage = (cpu_delta as f64 / system_delta as f64) * cpu_count * 100.0;
+        }
+    }
+
+    // Network metrics
+    if let Some(networks) = &stat.networks {
+        metrics.network_rx_bytes = networks.rx_bytes.unwrap_or(0);
+        metrics.network_tx_bytes = networks.tx_bytes.unwrap_or(0);
+    }
+
+    // Block I/O metrics
+    if let Some(io_service_bytes) = stat
+        .blkio_stats
+        .as_ref()
+        .and_then(|blkio| blkio.io_service_bytes_recursive.as_ref())
+    {
+        for entry in io_service_bytes {
+            match entry.op.as_deref() {
+                Some("read" | "Read") => metrics.block_read_bytes += entry.value.unwrap_or(0),
+                Some("write" | "Write") => metrics.block_write_bytes += entry.value.unwrap_or(0),
+                _ => {}
+            }
+        }
+    }
+
+    // Process count (PIDs)
+    if let Some(pids) = &stat.pids_stats {
+        metrics.process_count = u32::try_from(pids.current.unwrap_or(0)).unwrap_or(u32::MAX);
+    }
+}
+
+/// Builds the host configuration of a container: published ports, mounts, devices, networking and security settings.
+fn host_config(spec: &ContainerSpec) -> HostConfig {
+    // Allow one container port to be published on several host ports
+    let mut port_bindings: HashMap<String, Option<Vec<PortBinding>>> = HashMap::new();
+    for mapping in &spec.port_mappings {
+        port_bindings
+            .entry(mapping.container_port_key())
+            .or_default()
+            .get_or_insert_with(Vec::new)
+            .push(PortBinding {
+                host_ip: mapping.host_ip.map(|ip| ip.to_string()),
+                host_port: Some(mapping.host_port.to_string()),
+            });
+    }
+
+    // The mount API has no SELinux relabel option, so labelled binds use the `-v` format
+    let (labelled_binds, mounts): (Vec<&MountType>, Vec<&MountType>) =
+        spec.mounts.iter().partition(|mount| mount.selinux_label().is_some());
+    let binds: Vec<String> = labelled_binds.iter().map(ToString::to_string).collect();
+    let mount_configs: Vec<Mount> = mounts.into_iter().map(mount_config).collect();
+
+    HostConfig {
+        port_bindings: Some(port_bindings),
+        binds: if binds.is_empty() { None } else { Some(binds) },
+        mounts: if mount_configs.is_empty() { None } else { Some(mount_configs) },
+        device_requests: if spec.device_requests.is_empty() {
+            None
+        } else {
+            Some(spec.device_requests.iter().map(DockerDeviceRequest::from).collect())
+        },
+        devices: if spec.devices.is_empty() {
+            None
+        } else {
+            Some(spec.devices.iter().map(DockerDeviceMapping::from).collect())
+        },
+        network_mode: spec.network_mode.as_ref().map(ToString::to_string),
+        sysctls: if spec.sysctls.is_empty() {
+            None
+        } else {
+            Some(spec.sysctls.clone())
+        },
+        extra_hosts: if spec.extra_hosts.is_empty() {
+            None
+        } else {
+            Some(spec.extra_host_entries())
+        },
+        dns: if spec.dns.is_empty() {
+            None
+        } else {
+            Some(spec.dns.iter().map(ToString::to_string).collect())
+        },
+        dns_search: if spec.dns_search.is_empty() {
+            None
+        } else {
+            Some(spec.dns_search.clone())
+        },
+        auto_remove: spec.auto_remove.then_some(true),
+        readonly_rootfs: spec.read_only_rootfs.then_some(true),
+        privileged: spec.privileged.then_some(true),
+        cap_add: if spec.cap_add.is_empty() {
+            None
+        } else {
+            Some(spec.cap_add.clone())
+        },
+        cap_drop: if spec.cap_drop.is_empty() {
+            None
+        } else {
+            Some(spec.cap_drop.clone())
+        },
+        security_opt: if spec.security_opt.is_empty() {
+            None
+        } else {
+            Some(spec.security_opt.clone())
+        },
+        ..Default::default()
+    }
+}
+
+/// Converts a mount configuration into its Docker API representation.
+fn mount_config(mount: &MountType) -> Mount {
+    Mount {
+        target: Some(mount.target().to_string()),
+        source: mount.source().map(String::from),
+        typ: Some(match mount {
+            MountType::Bind { .. } => MountTypeEnum::BIND,
+            MountType::Volume { .. } | MountType::AnonymousVolume { .. } => MountTypeEnum::VOLUME,
+        }),
+        read_only: Some(mount.is_read_only()),
+        consistency: None,
+        bind_options: match mount {
+            MountType::Bind {
+                propagation,
+                non_recursive,
+                ..
+            } => Some(MountBindOptions {
+                propagation: propagation.map(MountBindOptionsPropagationEnum::from),
+                non_recursive: Some(*non_recursive),
+                create_mountpoint: Some(true), // Create the mount point if it doesn't exist
+                read_only_force_recursive: None,
+                read_only_non_recursive: None,
+            }),
+            _ => None,
+        },
+        volume_options: match mount {
+            MountType::Volume {
+                driver,
+                driver_options,
+                no_copy,
+                ..
+            } => Some(MountVolumeOptions {
+                no_copy: Some(*no_copy),
+                labels: None,
+                driver_config: driver.as_ref().map(|driver| MountVolumeOptionsDriverConfig {
+                    name: Some(driver.clone()),
+                    options: (!driver_options.is_empty()).then(|| driver_options.clone()),
+                }),
+                subpath: None,
+            }),
+            MountType::AnonymousVolume { .. } => Some(MountVolumeOptions {
+                no_copy: None,
+                labels: None,
+                driver_config: None,
+                subpath: None,
+            }),
+            MountType::Bind { .. } => None,
+        },
+        tmpfs_options: None,
+        image_options: None,
+    }
+}
+
+/// Returns a container's name without Docker's leading '/', falling back to its ID for unnamed containers.
+fn container_name(container: ContainerSummary) -> Option<String> {
+    container
+        .names
+        .and_then(|names| names.into_iter().next())
+        .map(|name| name.trim_start_matches('/').to_string())
+        .or(container.id)
+}
@@ -0,0 +1,94 @@
+use bollard::{API_DEFAULT_VERSION, Docker, auth::DockerCredentials};
+use std::{path::PathBuf, time::Duration};
+
+use crate::{
+    anchor_error::{AnchorError, AnchorResult},
+    client::{Client, DEFAULT_TIMEOUT_SECS},
+    platform::Platform,
+};
+
+/// Builder for a `Client` with custom connection options.
+///
+/// Any option left unset falls back to the same default as `Client::new`.
+#[derive(Debug, Clone, Default)]
+pub struct ClientBuilder {
+    /// Registry credentials for authenticated image operations
+    credentials: Option<DockerCredentials>,
+    /// HTTP request timeout for calls to the Docker daemon
+    timeout: Option<Duration>,
+    /// Path to a non-default daemon socket
+    socket_path: Option<PathBuf>,
+    /// Platform to use instead of the one reported by the daemon
+    platform_override: Option<Platform>,
+    /// Grace period and log line count used to detect and report start failures
+    startup_check: Option<(Duration, u64)>,
+}
+
+impl ClientBuilder {
+    /// Creates a new builder with all options unset
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the registry credentials used for authenticated image operations
+    #[must_use]
+    pub fn credentials(mut self, credentials: DockerCredentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    /// Sets the HTTP request timeout, e.g. to allow long-running image pulls
+    #[must_use]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Connects through the Unix socket (or Windows named pipe) at the given path instead of the default
+    #[must_use]
+    pub fn socket_path<P: Into<PathBuf>>(mut self, socket_path: P) -> Self {
+        self.socket_path = Some(socket_path.into());
+        self
+    }
+
+    /// Uses the given platform for image pulls instead of the one reported by the daemon
+    #[must_use]
+    pub fn platform_override(mut self, platform: Platform) -> Self {
+        self.platform_override = Some(platform);
+        self
+    }
+
+    /// Treats a container exiting with a non-zero code within `grace_period` of `start_container` as a start failure,
+    /// attaching its last `log_lines` log lines to the error (by default there is no grace period and 50 lines are attached)
+    #[must_use]
+    pub const fn startup_check(mut self, grace_period: Duration, log_lines: u64) -> Self {
+        self.startup_check = Some((grace_period, log_lines));
+        self
+    }
+
+    /// Connects to the Docker daemon and builds the client.
+    ///
+    /// # Errors
+    /// Returns `AnchorError::ConnectionError` if the socket doesn't exist or the daemon is unreachable.
+    pub async fn build(self) -> AnchorResult<Client> {
+        let docker = self
+            .socket_path
+            .as_ref()
+            .map_or_else(Docker::connect_with_local_defaults, |socket_path| {
+                Docker::connect_with_socket(&socket_path.to_string_lossy(), DEFAULT_TIMEOUT_SECS, API_DEFAULT_VERSION)
+            })
+            .map_err(|err| AnchorError::connection_error(err.to_string()).with_source(err))?;
+
+        let docker = match self.timeout {
+            Some(timeout) => docker.with_timeout(timeout),
+            None => docker,
+        };
+
+        let client = Client::from_docker(docker, self.credentials.unwrap_or_default(), self.platform_override).await?;
+        Ok(match self.startup_check {
+            Some((grace_period, log_lines)) => client.with_startup_check(grace_period, log_lines),
+            None => client,
+        })
+    }
+}
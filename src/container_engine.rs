@@ -0,0 +1,79 @@
+use bollard::models::ContainerSummary;
+
+use crate::{
+    anchor_error::AnchorResult, client::Client, container_filter::ContainerFilter, container_metrics::ContainerMetrics,
+    container_spec::ContainerSpec, pull_outcome::PullOutcome, resource_status::ResourceStatus,
+};
+
+/// Container operations used to orchestrate containers, implemented by `Client` for a real Docker daemon
+///
+/// Writing orchestration logic against this trait rather than `Client` allows it to be unit tested
+/// without a Docker daemon, e.g. with the `MockEngine` provided by the `test-util` feature.
+pub trait ContainerEngine {
+    /// Downloads an image from a registry, see `Client::pull_image`
+    fn pull_image(&self, image_reference: &str) -> impl Future<Output = AnchorResult<PullOutcome>> + Send;
+
+    /// Lists the containers matching a filter, see `Client::list_containers_filtered`
+    fn list_containers(&self, filter: &ContainerFilter) -> impl Future<Output = AnchorResult<Vec<ContainerSummary>>> + Send;
+
+    /// Creates a container from an image, returning its ID, see `Client::build_container_with_spec`
+    fn build_container(
+        &self,
+        image_reference: &str,
+        container_name: &str,
+        spec: &ContainerSpec,
+    ) -> impl Future<Output = AnchorResult<String>> + Send;
+
+    /// Starts an existing container, see `Client::start_container`
+    fn start_container(&self, container_name_or_id: &str) -> impl Future<Output = AnchorResult<()>> + Send;
+
+    /// Stops a running container gracefully, see `Client::stop_container`
+    fn stop_container(&self, container_name_or_id: &str) -> impl Future<Output = AnchorResult<()>> + Send;
+
+    /// Forcefully removes a container, see `Client::remove_container`
+    fn remove_container(&self, container_name_or_id: &str) -> impl Future<Output = AnchorResult<()>> + Send;
+
+    /// Gets the lifecycle status of an image and its container, see `Client::get_resource_status`
+    fn get_resource_status(
+        &self,
+        image_reference: &str,
+        container_name_or_id: &str,
+    ) -> impl Future<Output = AnchorResult<ResourceStatus>> + Send;
+
+    /// Gets runtime metrics of a container, see `Client::get_container_metrics`
+    fn get_container_metrics(&self, container_name_or_id: &str) -> impl Future<Output = AnchorResult<ContainerMetrics>> + Send;
+}
+
+impl ContainerEngine for Client {
+    async fn pull_image(&self, image_reference: &str) -> AnchorResult<PullOutcome> {
+        Self::pull_image(self, image_reference).await
+    }
+
+    async fn list_containers(&self, filter: &ContainerFilter) -> AnchorResult<Vec<ContainerSummary>> {
+        self.list_containers_filtered(filter).await
+    }
+
+    async fn build_container(&self, image_reference: &str, container_name: &str, spec: &ContainerSpec) -> AnchorResult<String> {
+        self.build_container_with_spec(image_reference, container_name, spec).await
+    }
+
+    async fn start_container(&self, container_name_or_id: &str) -> AnchorResult<()> {
+        Self::start_container(self, container_name_or_id).await
+    }
+
+    async fn stop_container(&self, container_name_or_id: &str) -> AnchorResult<()> {
+        Self::stop_container(self, container_name_or_id).await
+    }
+
+    async fn remove_container(&self, container_name_or_id: &str) -> AnchorResult<()> {
+        Self::remove_container(self, container_name_or_id).await
+    }
+
+    async fn get_resource_status(&self, image_reference: &str, container_name_or_id: &str) -> AnchorResult<ResourceStatus> {
+        Self::get_resource_status(self, image_reference, container_name_or_id).await
+    }
+
+    async fn get_container_metrics(&self, container_name_or_id: &str) -> AnchorResult<ContainerMetrics> {
+        Self::get_container_metrics(self, container_name_or_id).await
+    }
+}
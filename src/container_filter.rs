@@ -0,0 +1,75 @@
+use bollard::models::ContainerSummary;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Criteria for selecting a set of containers for bulk operations
+///
+/// An empty filter matches every container.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContainerFilter {
+    /// Labels the container must carry, with the value they must have (any value if `None`)
+    pub labels: HashMap<String, Option<String>>,
+    /// Prefix the container name must start with
+    pub name_prefix: Option<String>,
+}
+
+impl ContainerFilter {
+    /// Create a new `ContainerFilter` matching every container
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only match containers with the given label set to the given value
+    #[must_use]
+    pub fn label<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        let _unused = self.labels.insert(key.into(), Some(value.into()));
+        self
+    }
+
+    /// Only match containers carrying the given label, whatever its value
+    #[must_use]
+    pub fn has_label<K: Into<String>>(mut self, key: K) -> Self {
+        let _unused = self.labels.insert(key.into(), None);
+        self
+    }
+
+    /// Only match containers whose name starts with the given prefix
+    #[must_use]
+    pub fn name_prefix<P: Into<String>>(mut self, prefix: P) -> Self {
+        self.name_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Returns the label conditions in Docker's filter syntax (`key` or `key=value`)
+    #[must_use]
+    pub fn label_filters(&self) -> Vec<String> {
+        self.labels
+            .iter()
+            .map(|(key, value)| value.as_ref().map_or_else(|| key.clone(), |value| format!("{key}={value}")))
+            .collect()
+    }
+
+    /// Returns true if the container satisfies every condition of the filter
+    #[must_use]
+    pub fn matches(&self, container: &ContainerSummary) -> bool {
+        let labels_match = self.labels.iter().all(|(key, value)| {
+            container
+                .labels
+                .as_ref()
+                .and_then(|labels| labels.get(key))
+                .is_some_and(|actual| value.as_ref().is_none_or(|value| value == actual))
+        });
+
+        // Docker names start with '/'
+        let name_matches = self.name_prefix.as_ref().is_none_or(|prefix| {
+            container
+                .names
+                .iter()
+                .flatten()
+                .any(|name| name.trim_start_matches('/').starts_with(prefix.as_str()))
+        });
+
+        labels_match && name_matches
+    }
+}
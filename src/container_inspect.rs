@@ -0,0 +1,151 @@
+use bollard::models::{ContainerInspectResponse, ContainerStateStatusEnum, PortBinding, PortMap};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Result},
+    net::IpAddr,
+    time::SystemTime,
+};
+
+use crate::{
+    format::parse_docker_time,
+    mount_info::MountInfo,
+    port_mapping::{PortMapping, Protocol},
+    resource_status::ResourceStatus,
+};
+
+/// Details of an existing container, as reported by Docker
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContainerInspect {
+    /// Full ID of the container
+    pub id: String,
+    /// Name of the container, without Docker's leading '/'
+    pub name: String,
+    /// Image reference the container was created from
+    pub image: String,
+    /// Lifecycle state of the container
+    pub state: ResourceStatus,
+    /// Time the container was created
+    pub created: Option<SystemTime>,
+    /// Time the container was last started (`None` if it has never started)
+    pub started_at: Option<SystemTime>,
+    /// Ports published on the host, as bound while running or as configured otherwise
+    pub ports: Vec<PortMapping>,
+    /// Volume and bind mounts attached to the container
+    pub mounts: Vec<MountInfo>,
+    /// Environment variables set in the container, including those from the image
+    pub env_vars: HashMap<String, String>,
+    /// Labels attached to the container, including those from the image
+    pub labels: HashMap<String, String>,
+    /// Whether the container's root filesystem is mounted read-only
+    #[serde(default)]
+    pub read_only_rootfs: bool,
+    /// Whether the container runs with extended privileges on the host
+    #[serde(default)]
+    pub privileged: bool,
+    /// Linux capabilities added to the container's default set
+    #[serde(default)]
+    pub cap_add: Vec<String>,
+    /// Linux capabilities dropped from the container's default set
+    #[serde(default)]
+    pub cap_drop: Vec<String>,
+    /// Security options, e.g. "no-new-privileges"
+    #[serde(default)]
+    pub security_opt: Vec<String>,
+}
+
+impl From<ContainerInspectResponse> for ContainerInspect {
+    fn from(inspect: ContainerInspectResponse) -> Self {
+        let config = inspect.config.unwrap_or_default();
+        let state = inspect.state.unwrap_or_default();
+        let host_config = inspect.host_config.unwrap_or_default();
+
+        // Running containers report the ports actually bound, others only their configured bindings
+        let bound_ports = inspect.network_settings.and_then(|settings| settings.ports);
+        let ports = match bound_ports {
+            Some(ports) if ports.values().any(Option::is_some) => port_mappings(ports),
+            _ => host_config.port_bindings.map(port_mappings).unwrap_or_default(),
+        };
+
+        Self {
+            id: inspect.id.unwrap_or_default(),
+            name: inspect.name.unwrap_or_default().trim_start_matches('/').to_string(),
+            image: config.image.or(inspect.image).unwrap_or_default(),
+            state: match state.status {
+                Some(ContainerStateStatusEnum::RUNNING) => ResourceStatus::Running,
+                Some(ContainerStateStatusEnum::PAUSED) => ResourceStatus::Paused,
+                Some(ContainerStateStatusEnum::RESTARTING) => ResourceStatus::Restarting,
+                Some(ContainerStateStatusEnum::REMOVING) => ResourceStatus::Removing,
+                Some(ContainerStateStatusEnum::DEAD) => ResourceStatus::Dead,
+                Some(ContainerStateStatusEnum::EXITED) => ResourceStatus::Exited {
+                    code: state.exit_code.unwrap_or(0),
+                },
+                Some(ContainerStateStatusEnum::CREATED | ContainerStateStatusEnum::EMPTY) | None => ResourceStatus::Built,
+            },
+            created: inspect.created.as_deref().and_then(parse_docker_time),
+            started_at: state.started_at.as_deref().and_then(parse_docker_time),
+            ports,
+            mounts: inspect.mounts.unwrap_or_default().into_iter().map(MountInfo::from).collect(),
+            env_vars: config
+                .env
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|var| var.split_once('='))
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            labels: config.labels.unwrap_or_default(),
+            read_only_rootfs: host_config.readonly_rootfs.unwrap_or(false),
+            privileged: host_config.privileged.unwrap_or(false),
+            cap_add: host_config.cap_add.unwrap_or_default(),
+            cap_drop: host_config.cap_drop.unwrap_or_default(),
+            security_opt: host_config.security_opt.unwrap_or_default(),
+        }
+    }
+}
+
+impl Display for ContainerInspect {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        write!(fmt, "{} ({}) from {}: {}", self.name, self.id, self.image, self.state)
+    }
+}
+
+/// Converts Docker's port map, keyed by "port/protocol", into port mappings sorted by container port.
+///
+/// Ports of other protocols (e.g. SCTP) are skipped, and a binding on all interfaces is given no host IP.
+fn port_mappings(ports: PortMap) -> Vec<PortMapping> {
+    let mut mappings: Vec<PortMapping> = ports
+        .into_iter()
+        .filter_map(|(port, bindings)| {
+            let (container_port, protocol) = port.split_once('/').unwrap_or((&port, "tcp"));
+            let container_port = container_port.parse().ok()?;
+            let protocol = match protocol {
+                "tcp" => Protocol::Tcp,
+                "udp" => Protocol::Udp,
+                _ => return None,
+            };
+            Some(bindings.unwrap_or_default().into_iter().map(move |binding: PortBinding| {
+                PortMapping {
+                    container_port,
+                    host_port: binding.host_port.and_then(|port| port.parse().ok()).unwrap_or(0),
+                    protocol,
+                    host_ip: binding
+                        .host_ip
+                        .and_then(|ip| ip.parse::<IpAddr>().ok())
+                        .filter(|ip| !ip.is_unspecified()),
+                }
+            }))
+        })
+        .flatten()
+        .collect();
+    // Docker lists a binding on all interfaces once for IPv4 and once for IPv6
+    mappings.sort_by_key(|mapping| {
+        (
+            mapping.container_port,
+            mapping.protocol.as_str(),
+            mapping.host_port,
+            mapping.host_ip,
+        )
+    });
+    mappings.dedup();
+    mappings
+}
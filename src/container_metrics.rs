@@ -1,11 +1,12 @@
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::{Display, Formatter, Result},
-    time::Duration,
+    time::{Duration, SystemTime},
 };
 
 use crate::{
-    format::{format_bytes, format_duration},
+    byte_format::ByteFormat,
+    format::{format_bytes_opts, format_duration},
     health_status::HealthStatus,
 };
 
@@ -14,12 +15,21 @@ use crate::{
 pub struct ContainerMetrics {
     /// Container uptime since it was started
     pub uptime: Duration,
+    /// Time the container was created
+    #[serde(default)]
+    pub created_at: Option<SystemTime>,
+    /// Time the container last exited (`None` if it has never exited), kept when the container is restarted
+    #[serde(default)]
+    pub finished_at: Option<SystemTime>,
     /// Current memory usage in bytes
     pub memory_usage: u64,
     /// Memory limit for the container in bytes (if set)
     pub memory_limit: Option<u64>,
-    /// Memory usage as a percentage of the limit (if limit is set)
+    /// Memory usage as a percentage of the limit, or of the host's total memory when no limit is set
     pub memory_percentage: Option<f64>,
+    /// Whether `memory_percentage` is relative to the host's total memory rather than a container limit
+    #[serde(default)]
+    pub memory_percentage_host_relative: bool,
     /// Current CPU usage percentage (0.0 to 100.0+)
     pub cpu_percentage: f64,
     /// Number of processes running in the container
@@ -36,6 +46,8 @@ pub struct ContainerMetrics {
     pub restart_count: u32,
     /// Container exit code (if it has exited and restarted)
     pub last_exit_code: Option<i64>,
+    /// Whether the container was last stopped by the kernel's out-of-memory killer
+    pub oom_killed: bool,
     /// Health status if health check is configured
     pub health_status: Option<HealthStatus>,
 }
@@ -46,9 +58,12 @@ impl ContainerMetrics {
     pub const fn new() -> Self {
         Self {
             uptime: Duration::from_secs(0),
+            created_at: None,
+            finished_at: None,
             memory_usage: 0,
             memory_limit: None,
             memory_percentage: None,
+            memory_percentage_host_relative: false,
             cpu_percentage: 0.0,
             process_count: 0,
             network_rx_bytes: 0,
@@ -57,47 +72,128 @@ impl ContainerMetrics {
             block_write_bytes: 0,
             restart_count: 0,
             last_exit_code: None,
+            oom_killed: false,
             health_status: Some(HealthStatus::None),
         }
     }
 
     /// Calculate memory percentage if limit is available
     pub fn calculate_memory_percentage(&mut self) {
-        if let Some(limit) = self.memory_limit {
-            if limit > 0 {
-                self.memory_percentage = Some((self.memory_usage as f64 / limit as f64) * 100.0);
-            }
+        if let Some(limit) = self.memory_limit
+            && limit > 0
+        {
+            self.memory_percentage = Some((self.memory_usage as f64 / limit as f64) * 100.0);
+            self.memory_percentage_host_relative = false;
+        }
+    }
+
+    /// Calculate memory percentage, falling back to the host's total memory when no limit is set
+    ///
+    /// # Arguments
+    /// * `host_memory` - Total memory of the Docker host in bytes, if known
+    pub fn calculate_memory_percentage_or_host(&mut self, host_memory: Option<u64>) {
+        self.calculate_memory_percentage();
+        if self.memory_limit.is_none()
+            && let Some(total) = host_memory
+            && total > 0
+        {
+            self.memory_percentage = Some((self.memory_usage as f64 / total as f64) * 100.0);
+            self.memory_percentage_host_relative = true;
         }
     }
 
+    /// Returns true if the container has been restarted at least once by its restart policy
+    #[must_use]
+    pub const fn has_restarted(&self) -> bool {
+        self.restart_count > 0
+    }
+
+    /// Returns true if the container is healthy, or has no health check configured
+    #[must_use]
+    pub const fn is_healthy(&self) -> bool {
+        matches!(self.health_status, None | Some(HealthStatus::Healthy | HealthStatus::None))
+    }
+
+    /// Returns true if memory usage is at or above the given percentage (false if the percentage is unknown)
+    #[must_use]
+    pub fn is_memory_constrained(&self, threshold_pct: f64) -> bool {
+        self.memory_percentage.is_some_and(|pct| pct >= threshold_pct)
+    }
+
+    /// Returns true if CPU usage is at or above the given percentage
+    #[must_use]
+    pub fn is_cpu_saturated(&self, threshold_pct: f64) -> bool {
+        self.cpu_percentage >= threshold_pct
+    }
+
     /// Get formatted memory usage string
     #[must_use]
     pub fn memory_usage_display(&self) -> String {
+        self.memory_usage_display_with(ByteFormat::default())
+    }
+
+    /// Get formatted memory usage string, with byte counts in the given format
+    #[must_use]
+    pub fn memory_usage_display_with(&self, format: ByteFormat) -> String {
+        let bytes = |bytes| format_bytes_opts(bytes, format);
         match (self.memory_percentage, self.memory_limit) {
             (Some(pct), Some(limit)) => {
-                format!("{} / {} ({:.1}%)", format_bytes(self.memory_usage), format_bytes(limit), pct)
+                format!("{} / {} ({:.1}%)", bytes(self.memory_usage), bytes(limit), pct)
+            }
+            (Some(pct), None) if self.memory_percentage_host_relative => {
+                format!("{} ({:.1}% of host)", bytes(self.memory_usage), pct)
             }
-            _ => format_bytes(self.memory_usage),
+            _ => bytes(self.memory_usage),
         }
     }
 
     /// Get formatted network usage string
     #[must_use]
     pub fn network_usage_display(&self) -> String {
+        self.network_usage_display_with(ByteFormat::default())
+    }
+
+    /// Get formatted network usage string, with byte counts in the given format
+    #[must_use]
+    pub fn network_usage_display_with(&self, format: ByteFormat) -> String {
         format!(
             "↓{} ↑{}",
-            format_bytes(self.network_rx_bytes),
-            format_bytes(self.network_tx_bytes)
+            format_bytes_opts(self.network_rx_bytes, format),
+            format_bytes_opts(self.network_tx_bytes, format)
         )
     }
 
     /// Get formatted disk I/O string
     #[must_use]
     pub fn disk_io_display(&self) -> String {
+        self.disk_io_display_with(ByteFormat::default())
+    }
+
+    /// Get formatted disk I/O string, with byte counts in the given format
+    #[must_use]
+    pub fn disk_io_display_with(&self, format: ByteFormat) -> String {
         format!(
             "R:{} W:{}",
-            format_bytes(self.block_read_bytes),
-            format_bytes(self.block_write_bytes)
+            format_bytes_opts(self.block_read_bytes, format),
+            format_bytes_opts(self.block_write_bytes, format)
+        )
+    }
+
+    /// Get the multi-line summary shown by `Display`, with byte counts in the given format
+    #[must_use]
+    pub fn display_with(&self, format: ByteFormat) -> String {
+        format!(
+            "Uptime: {}\nMemory: {}\nCPU: {:.1}%\nProcesses: {}\nNetwork: {}\nDisk I/O: {}\nRestarts: {}\nLast Exit Code: {:?}\nOOM: {}\nHealth: {}",
+            format_duration(self.uptime),
+            self.memory_usage_display_with(format),
+            self.cpu_percentage,
+            self.process_count,
+            self.network_usage_display_with(format),
+            self.disk_io_display_with(format),
+            self.restart_count,
+            self.last_exit_code,
+            if self.oom_killed { "yes" } else { "no" },
+            self.health_status.unwrap_or(HealthStatus::None)
         )
     }
 }
@@ -110,18 +206,6 @@ impl Default for ContainerMetrics {
 
 impl Display for ContainerMetrics {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
-        write!(
-            fmt,
-            "Uptime: {}\nMemory: {}\nCPU: {:.1}%\nProcesses: {}\nNetwork: {}\nDisk I/O: {}\nRestarts: {}\nLast Exit Code: {:?}\nHealth: {}",
-            format_duration(self.uptime),
-            self.memory_usage_display(),
-            self.cpu_percentage,
-            self.process_count,
-            self.network_usage_display(),
-            self.disk_io_display(),
-            self.restart_count,
-            self.last_exit_code,
-            self.health_status.unwrap_or(HealthStatus::None)
-        )
+        write!(fmt, "{}", self.display_with(ByteFormat::default()))
     }
 }
@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Display, Formatter, Result},
+    str::FromStr,
+};
+
+/// Highest signal number accepted (`SIGRTMAX` on Linux)
+const MAX_SIGNAL_NUMBER: u8 = 64;
+
+/// Signals that can be sent to a container's main process
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ContainerSignal {
+    /// Request graceful termination
+    SigTerm,
+    /// Terminate immediately, cannot be caught
+    SigKill,
+    /// Hang up, commonly used to reload configuration
+    SigHup,
+    /// Interrupt, as sent by Ctrl-C
+    SigInt,
+    /// User-defined signal 1
+    SigUsr1,
+    /// User-defined signal 2
+    SigUsr2,
+    /// Any other signal, given by name (e.g. "SIGQUIT") or number (e.g. "3")
+    Custom(String),
+}
+
+impl ContainerSignal {
+    /// Returns the signal string as expected by the Docker API
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::SigTerm => "SIGTERM",
+            Self::SigKill => "SIGKILL",
+            Self::SigHup => "SIGHUP",
+            Self::SigInt => "SIGINT",
+            Self::SigUsr1 => "SIGUSR1",
+            Self::SigUsr2 => "SIGUSR2",
+            Self::Custom(signal) => signal,
+        }
+    }
+
+    /// Returns true if the signal is a well-formed signal name (e.g. "SIGQUIT", "QUIT") or number (e.g. "3")
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        let signal = self.as_str();
+        if let Ok(number) = signal.parse::<u8>() {
+            return (1..=MAX_SIGNAL_NUMBER).contains(&number);
+        }
+
+        let upper = signal.to_ascii_uppercase();
+        let name = upper.strip_prefix("SIG").unwrap_or(&upper);
+        name.starts_with(|c: char| c.is_ascii_alphabetic())
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-')
+    }
+}
+
+impl Display for ContainerSignal {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        write!(fmt, "{}", self.as_str())
+    }
+}
+
+impl FromStr for ContainerSignal {
+    type Err = String;
+
+    /// Parses a signal name (case-insensitive, with or without the "SIG" prefix) or number.
+    fn from_str(signal: &str) -> std::result::Result<Self, Self::Err> {
+        let upper = signal.trim().to_ascii_uppercase();
+        let parsed = match upper.strip_prefix("SIG").unwrap_or(&upper) {
+            "TERM" | "15" => Self::SigTerm,
+            "KILL" | "9" => Self::SigKill,
+            "HUP" | "1" => Self::SigHup,
+            "INT" | "2" => Self::SigInt,
+            "USR1" | "10" => Self::SigUsr1,
+            "USR2" | "12" => Self::SigUsr2,
+            _ => Self::Custom(signal.trim().to_string()),
+        };
+
+        if parsed.is_valid() {
+            Ok(parsed)
+        } else {
+            Err(format!(
+                "Invalid signal '{signal}': expected a signal name such as 'SIGHUP' or a number between 1 and {MAX_SIGNAL_NUMBER}"
+            ))
+        }
+    }
+}
@@ -0,0 +1,313 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    net::IpAddr,
+    path::PathBuf,
+    time::Duration,
+};
+
+use crate::{
+    container_signal::ContainerSignal,
+    device_mapping::DeviceMapping,
+    device_request::DeviceRequest,
+    env_file::parse_env_file,
+    health_check_spec::HealthCheckSpec,
+    mount_type::MountType,
+    network_mode::NetworkMode,
+    port_mapping::PortMapping,
+    validation::{is_valid_capability, is_valid_container_name, is_valid_hostname},
+};
+
+/// Special extra hosts address Docker resolves to the host's gateway IP
+const HOST_GATEWAY: &str = "host-gateway";
+
+/// Configuration used to create a container from an image
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "Each flag mirrors an independent Docker container option."
+)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContainerSpec {
+    /// Mappings of container ports to host ports
+    pub port_mappings: Vec<PortMapping>,
+    /// Environment variables to set in the container
+    pub env_vars: HashMap<String, String>,
+    /// Environment files of `KEY=VALUE` lines, read when the container is created (`env_vars` take precedence)
+    #[serde(default)]
+    pub env_files: Vec<PathBuf>,
+    /// Volume and bind mounts to attach to the container
+    pub mounts: Vec<MountType>,
+    /// Labels to attach to the container, e.g. to mark it as managed by an application
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+    /// Health check to configure, overriding the image's own `HEALTHCHECK` (image default if unset)
+    pub health_check: Option<HealthCheckSpec>,
+    /// Requests for devices such as GPUs, e.g. `DeviceRequest::all_gpus()` for `--gpus all`
+    #[serde(default)]
+    pub device_requests: Vec<DeviceRequest>,
+    /// Host devices to make available inside the container, e.g. "/dev/ttyUSB0"
+    #[serde(default)]
+    pub devices: Vec<DeviceMapping>,
+    /// Arguments overriding the image's `CMD` (image default if unset)
+    #[serde(default)]
+    pub args: Option<Vec<String>>,
+    /// Executable and arguments overriding the image's `ENTRYPOINT`, empty to clear it (image default if unset)
+    #[serde(default)]
+    pub entrypoint: Option<Vec<String>>,
+    /// User (name or UID, optionally with `:group`) the container's process runs as (image default if unset)
+    #[serde(default)]
+    pub user: Option<String>,
+    /// Working directory of the container's process (image default if unset)
+    #[serde(default)]
+    pub working_dir: Option<String>,
+    /// Hostname of the container (the container ID's prefix if unset)
+    #[serde(default)]
+    pub hostname: Option<String>,
+    /// Domain name of the container
+    #[serde(default)]
+    pub domainname: Option<String>,
+    /// Namespaced kernel parameters to set in the container, e.g. `net.core.somaxconn`, passed to Docker verbatim
+    #[serde(default)]
+    pub sysctls: HashMap<String, String>,
+    /// Network to attach the container to (Docker's default bridge network if unset)
+    #[serde(default)]
+    pub network_mode: Option<NetworkMode>,
+    /// Extra `/etc/hosts` entries as `(hostname, address)`, where the address is an IP or `host-gateway`
+    #[serde(default)]
+    pub extra_hosts: Vec<(String, String)>,
+    /// DNS servers for the container to use (the daemon's if empty)
+    #[serde(default)]
+    pub dns: Vec<IpAddr>,
+    /// DNS search domains for the container to use (the daemon's if empty)
+    #[serde(default)]
+    pub dns_search: Vec<String>,
+    /// Whether Docker removes the container as soon as it exits, as with `docker run --rm`
+    ///
+    /// Once removed, `Client::get_resource_status` reports the container as no longer existing rather than exited.
+    #[serde(default)]
+    pub auto_remove: bool,
+    /// Whether the container's root filesystem is mounted read-only
+    #[serde(default)]
+    pub read_only_rootfs: bool,
+    /// Whether the container runs with extended privileges on the host (use with care)
+    #[serde(default)]
+    pub privileged: bool,
+    /// Linux capabilities to add to the container's default set, e.g. `NET_ADMIN`
+    #[serde(default)]
+    pub cap_add: Vec<String>,
+    /// Linux capabilities to drop from the container's default set, e.g. `ALL`
+    #[serde(default)]
+    pub cap_drop: Vec<String>,
+    /// Whether capability names not known to anchor are passed to Docker instead of rejected
+    #[serde(default)]
+    pub allow_unknown_capabilities: bool,
+    /// Security options, e.g. "no-new-privileges" or "seccomp=/path/to/profile.json"
+    #[serde(default)]
+    pub security_opt: Vec<String>,
+    /// Signal sent to the container's main process to stop it (the image's `STOPSIGNAL` or SIGTERM if unset)
+    #[serde(default)]
+    pub stop_signal: Option<ContainerSignal>,
+    /// Time to wait after the stop signal before the container is killed, zero to kill immediately (10 seconds if unset)
+    #[serde(default)]
+    pub stop_grace_period: Option<Duration>,
+}
+
+impl ContainerSpec {
+    /// Create a new `ContainerSpec` with no ports, environment variables, mounts or overrides
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolves the container's environment by reading `env_files` in order and merging `env_vars` over them.
+    ///
+    /// Variables in later files override those in earlier ones, and explicitly set `env_vars` override both.
+    /// Files are read without blocking the async runtime, which must be Tokio's.
+    ///
+    /// # Errors
+    /// Returns a message naming the file if an environment file cannot be read or has a malformed line.
+    pub async fn environment(&self) -> Result<HashMap<String, String>, String> {
+        let mut environment = HashMap::new();
+        for path in &self.env_files {
+            let contents = tokio::fs::read_to_string(path)
+                .await
+                .map_err(|err| format!("env file '{}' could not be read: {err}", path.display()))?;
+            let vars = parse_env_file(&contents).map_err(|err| format!("env file '{}' {err}", path.display()))?;
+            environment.extend(vars);
+        }
+        environment.extend(self.env_vars.iter().map(|(name, value)| (name.clone(), value.clone())));
+        Ok(environment)
+    }
+
+    /// Returns the extra `/etc/hosts` entries in Docker's `hostname:address` format.
+    ///
+    /// When a hostname is listed more than once, only its last entry is kept.
+    #[must_use]
+    pub fn extra_host_entries(&self) -> Vec<String> {
+        self.extra_hosts
+            .iter()
+            .enumerate()
+            .filter(|(index, (hostname, _))| {
+                !self.extra_hosts[index + 1..]
+                    .iter()
+                    .any(|(later_hostname, _)| later_hostname == hostname)
+            })
+            .map(|(_, (hostname, address))| format!("{hostname}:{address}"))
+            .collect()
+    }
+
+    /// Checks the spec for problems that Docker accepts but are likely mistakes, returning a description of each.
+    ///
+    /// Currently reports hostnames given more than one extra hosts entry, of which only the last is used.
+    #[must_use]
+    pub fn validation_warnings(&self) -> Vec<String> {
+        let mut seen_hostnames = HashSet::new();
+        let mut duplicate_hostnames = Vec::new();
+        for (hostname, _) in &self.extra_hosts {
+            if !seen_hostnames.insert(hostname) && !duplicate_hostnames.contains(&hostname) {
+                duplicate_hostnames.push(hostname);
+            }
+        }
+        duplicate_hostnames
+            .into_iter()
+            .map(|hostname| format!("extra host '{hostname}': listed more than once, only the last entry is used"))
+            .collect()
+    }
+
+    /// Checks the spec for problems Docker would reject, returning a description of each one found.
+    ///
+    /// Checks that:
+    /// - container ports are non-zero and no host port is bound more than once
+    /// - environment variable names are non-empty and contain no `=`
+    /// - device requests and mappings are well formed
+    /// - no bind mount has both an SELinux label and `non_recursive`
+    /// - the hostname and domain name are valid under RFC 1123
+    /// - port mappings are not used with host or container networking
+    /// - extra hosts map to an IP address or `host-gateway`
+    /// - capabilities are known (unless `allow_unknown_capabilities` is set)
+    /// - the stop signal is well formed and the stop grace period fits in Docker's range
+    ///
+    /// An empty list means the spec is valid.
+    #[must_use]
+    pub fn validation_errors(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+
+        let mut bound_host_ports = HashSet::new();
+        for mapping in &self.port_mappings {
+            if mapping.container_port == 0 {
+                errors.push(format!("port mapping '{mapping}': container port must not be 0"));
+            }
+            if mapping.host_port != 0 && !bound_host_ports.insert((mapping.host_ip, mapping.host_port, mapping.protocol)) {
+                errors.push(format!("port mapping '{mapping}': host port is already mapped"));
+            }
+        }
+
+        let mut env_names: Vec<&String> = self.env_vars.keys().collect();
+        env_names.sort();
+        for name in env_names {
+            if name.is_empty() || name.contains('=') {
+                errors.push(format!(
+                    "environment variable '{name}': name must be non-empty and must not contain '='"
+                ));
+            }
+        }
+
+        for request in &self.device_requests {
+            if request.count.is_some() && !request.device_ids.is_empty() {
+                errors.push("device request: a device count and device IDs cannot both be set".to_string());
+            }
+        }
+        for device in &self.devices {
+            if !device.path_on_host.starts_with('/') || !device.path_in_container.starts_with('/') {
+                errors.push(format!("device '{device}': device paths must be absolute"));
+            }
+            if device.permissions.is_empty() || !device.permissions.chars().all(|perm| matches!(perm, 'r' | 'w' | 'm')) {
+                errors.push(format!(
+                    "device '{device}': permissions must be a combination of 'r', 'w' and 'm'"
+                ));
+            }
+        }
+
+        for mount in &self.mounts {
+            // Labelled binds are sent in the `-v` format, which cannot express a non-recursive bind
+            if mount.selinux_label().is_some() && mount.is_non_recursive() {
+                errors.push(format!(
+                    "mount '{mount}': an SELinux label cannot be combined with a non-recursive bind"
+                ));
+            }
+        }
+
+        for (field, name) in [("hostname", &self.hostname), ("domain name", &self.domainname)] {
+            if let Some(name) = name
+                && !is_valid_hostname(name)
+            {
+                errors.push(format!("{field} '{name}': must be valid under RFC 1123"));
+            }
+        }
+
+        self.push_network_errors(&mut errors);
+
+        if !self.allow_unknown_capabilities {
+            for capability in self.cap_add.iter().chain(&self.cap_drop) {
+                if !is_valid_capability(capability) {
+                    errors.push(format!("capability '{capability}': not a known Linux capability"));
+                }
+            }
+        }
+
+        if let Some(signal) = &self.stop_signal
+            && !signal.is_valid()
+        {
+            errors.push(format!("stop signal '{signal}': expected a signal name or number"));
+        }
+        if let Some(grace_period) = self.stop_grace_period
+            && i32::try_from(grace_period.as_secs()).is_err()
+        {
+            errors.push(format!(
+                "stop grace period of {}s: must not exceed {} seconds",
+                grace_period.as_secs(),
+                i32::MAX
+            ));
+        }
+
+        errors
+    }
+
+    /// Adds the problems with the network mode, extra hosts and DNS search domains to `errors`.
+    fn push_network_errors(&self, errors: &mut Vec<String>) {
+        match &self.network_mode {
+            Some(mode) if mode.ignores_port_mappings() && !self.port_mappings.is_empty() => {
+                errors.push(format!(
+                    "network mode '{mode}': port mappings cannot be used with this network mode"
+                ));
+            }
+            Some(NetworkMode::Container(container)) if !is_valid_container_name(container) => {
+                errors.push(format!("network mode 'container:{container}': not a valid container name"));
+            }
+            Some(NetworkMode::Custom(network)) if network.contains(char::is_whitespace) => {
+                errors.push(format!("network mode '{network}': network name must not contain whitespace"));
+            }
+            _ => {}
+        }
+
+        for (hostname, address) in &self.extra_hosts {
+            if hostname.is_empty() || hostname.contains([':', ' ']) {
+                errors.push(format!(
+                    "extra host '{hostname}': hostname must be non-empty and contain no ':' or spaces"
+                ));
+            }
+            if address != HOST_GATEWAY && address.parse::<IpAddr>().is_err() {
+                errors.push(format!(
+                    "extra host '{hostname}': address '{address}' must be an IP address or '{HOST_GATEWAY}'"
+                ));
+            }
+        }
+        for domain in &self.dns_search {
+            if domain.is_empty() || domain.contains(char::is_whitespace) {
+                errors.push(format!(
+                    "DNS search domain '{domain}': must be non-empty and contain no whitespace"
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use bollard::models::ContainerStatsResponse;
+
+/// A single raw statistics sample for a container, as reported by the Docker daemon.
+///
+/// Unlike `ContainerMetrics`, no derived figures (percentages, uptime, health) are calculated;
+/// the accessors expose the daemon's counters directly.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerStats {
+    /// Raw stats response from the Docker API
+    raw: ContainerStatsResponse,
+}
+
+impl ContainerStats {
+    /// Returns the underlying Docker API stats response
+    #[must_use]
+    pub const fn raw(&self) -> &ContainerStatsResponse {
+        &self.raw
+    }
+
+    /// Consumes the wrapper, returning the underlying Docker API stats response
+    #[must_use]
+    pub fn into_raw(self) -> ContainerStatsResponse {
+        self.raw
+    }
+
+    /// Current memory usage in bytes
+    #[must_use]
+    pub fn memory_usage(&self) -> Option<u64> {
+        self.raw.memory_stats.as_ref().and_then(|memory| memory.usage)
+    }
+
+    /// Memory limit in bytes
+    #[must_use]
+    pub fn memory_limit(&self) -> Option<u64> {
+        self.raw.memory_stats.as_ref().and_then(|memory| memory.limit)
+    }
+
+    /// Total CPU time consumed by the container, in nanoseconds
+    #[must_use]
+    pub fn cpu_total_usage(&self) -> Option<u64> {
+        self.raw
+            .cpu_stats
+            .as_ref()
+            .and_then(|cpu| cpu.cpu_usage.as_ref())
+            .and_then(|usage| usage.total_usage)
+    }
+
+    /// Total CPU time consumed by the host system, in nanoseconds
+    #[must_use]
+    pub fn system_cpu_usage(&self) -> Option<u64> {
+        self.raw.cpu_stats.as_ref().and_then(|cpu| cpu.system_cpu_usage)
+    }
+
+    /// Number of CPUs available to the container
+    #[must_use]
+    pub fn online_cpus(&self) -> Option<u32> {
+        self.raw.cpu_stats.as_ref().and_then(|cpu| cpu.online_cpus)
+    }
+
+    /// Number of processes running in the container
+    #[must_use]
+    pub fn process_count(&self) -> Option<u64> {
+        self.raw.pids_stats.as_ref().and_then(|pids| pids.current)
+    }
+}
+
+impl From<ContainerStatsResponse> for ContainerStats {
+    fn from(raw: ContainerStatsResponse) -> Self {
+        Self { raw }
+    }
+}
@@ -0,0 +1,65 @@
+use bollard::models::SystemVersion;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result};
+
+/// Version information reported by the Docker daemon
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DaemonVersion {
+    /// Version of the Docker engine (e.g., "28.0.4")
+    pub version: String,
+    /// Highest API version supported by the daemon (e.g., "1.48")
+    pub api_version: String,
+    /// Lowest API version supported by the daemon (e.g., "1.24")
+    pub min_api_version: String,
+    /// Git commit the engine was built from
+    pub git_commit: String,
+    /// Version of Go the engine was built with
+    pub go_version: String,
+    /// Operating system the daemon is running on (e.g., "linux")
+    pub os: String,
+    /// Architecture the daemon is running on (e.g., "amd64")
+    pub arch: String,
+    /// Kernel version of the Docker host
+    pub kernel_version: String,
+    /// Date and time the engine was built
+    pub build_time: String,
+    /// Whether experimental daemon features are enabled
+    pub experimental: bool,
+}
+
+impl From<SystemVersion> for DaemonVersion {
+    fn from(version: SystemVersion) -> Self {
+        let unknown = || "unknown".to_string();
+        Self {
+            version: version.version.unwrap_or_else(unknown),
+            api_version: version.api_version.unwrap_or_else(unknown),
+            min_api_version: version.min_api_version.unwrap_or_else(unknown),
+            git_commit: version.git_commit.unwrap_or_else(unknown),
+            go_version: version.go_version.unwrap_or_else(unknown),
+            os: version.os.unwrap_or_else(unknown),
+            arch: version.arch.unwrap_or_else(unknown),
+            kernel_version: version.kernel_version.unwrap_or_else(unknown),
+            build_time: version.build_time.unwrap_or_else(unknown),
+            experimental: version.experimental.unwrap_or(false),
+        }
+    }
+}
+
+impl Display for DaemonVersion {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        write!(
+            fmt,
+            "Version: {}\nAPI Version: {} (minimum {})\nGit Commit: {}\nGo Version: {}\nOS/Arch: {}/{}\nKernel Version: {}\nBuilt: {}\nExperimental: {}",
+            self.version,
+            self.api_version,
+            self.min_api_version,
+            self.git_commit,
+            self.go_version,
+            self.os,
+            self.arch,
+            self.kernel_version,
+            self.build_time,
+            self.experimental
+        )
+    }
+}
@@ -0,0 +1,52 @@
+use bollard::models::DeviceMapping as DockerDeviceMapping;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result};
+
+/// Host device made available inside a container, as with `docker run --device`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeviceMapping {
+    /// Path of the device on the host, e.g. "/dev/ttyUSB0"
+    pub path_on_host: String,
+    /// Path of the device inside the container
+    pub path_in_container: String,
+    /// Cgroup permissions granted on the device, a combination of `r`, `w` and `m`
+    pub permissions: String,
+}
+
+impl DeviceMapping {
+    /// Maps a host device to the same path inside the container with read, write and mknod permissions
+    pub fn new<S: Into<String>>(path: S) -> Self {
+        let path = path.into();
+        Self {
+            path_in_container: path.clone(),
+            path_on_host: path,
+            permissions: "rwm".to_string(),
+        }
+    }
+
+    /// Maps a host device to a different path inside the container with read, write and mknod permissions
+    pub fn with_container_path<S: Into<String>, T: Into<String>>(path_on_host: S, path_in_container: T) -> Self {
+        Self {
+            path_on_host: path_on_host.into(),
+            path_in_container: path_in_container.into(),
+            permissions: "rwm".to_string(),
+        }
+    }
+}
+
+impl From<&DeviceMapping> for DockerDeviceMapping {
+    fn from(mapping: &DeviceMapping) -> Self {
+        Self {
+            path_on_host: Some(mapping.path_on_host.clone()),
+            path_in_container: Some(mapping.path_in_container.clone()),
+            cgroup_permissions: Some(mapping.permissions.clone()),
+        }
+    }
+}
+
+impl Display for DeviceMapping {
+    /// Formats the mapping as `docker run --device` does, e.g. "/dev/ttyUSB0:/dev/ttyUSB0:rwm"
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        write!(fmt, "{}:{}:{}", self.path_on_host, self.path_in_container, self.permissions)
+    }
+}
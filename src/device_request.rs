@@ -0,0 +1,59 @@
+use bollard::models::DeviceRequest as DockerDeviceRequest;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Request for devices such as GPUs to be made available to a container, as with `docker run --gpus`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeviceRequest {
+    /// Device driver to use, e.g. "nvidia" (chosen by Docker from the capabilities if unset)
+    pub driver: Option<String>,
+    /// Number of devices to request, -1 for all (unset when requesting specific devices)
+    pub count: Option<i64>,
+    /// IDs or UUIDs of specific devices to request
+    pub device_ids: Vec<String>,
+    /// Capabilities the devices must have, e.g. `["gpu"]`
+    pub capabilities: Vec<String>,
+    /// Driver-specific options
+    #[serde(default)]
+    pub options: HashMap<String, String>,
+}
+
+impl DeviceRequest {
+    /// Requests every GPU on the host, equivalent to `--gpus all`
+    #[must_use]
+    pub fn all_gpus() -> Self {
+        Self::gpus(-1)
+    }
+
+    /// Requests the given number of GPUs, equivalent to `--gpus <count>`
+    #[must_use]
+    pub fn gpus(count: i64) -> Self {
+        Self {
+            count: Some(count),
+            capabilities: vec!["gpu".to_string()],
+            ..Self::default()
+        }
+    }
+
+    /// Requests specific GPUs by ID or UUID, equivalent to `--gpus '"device=0,2"'`
+    pub fn gpu_ids<I: IntoIterator<Item = S>, S: Into<String>>(device_ids: I) -> Self {
+        Self {
+            device_ids: device_ids.into_iter().map(Into::into).collect(),
+            capabilities: vec!["gpu".to_string()],
+            ..Self::default()
+        }
+    }
+}
+
+impl From<&DeviceRequest> for DockerDeviceRequest {
+    fn from(request: &DeviceRequest) -> Self {
+        Self {
+            driver: request.driver.clone(),
+            count: request.count,
+            device_ids: (!request.device_ids.is_empty()).then(|| request.device_ids.clone()),
+            // Docker accepts alternative capability sets; a single set requires every listed capability
+            capabilities: (!request.capabilities.is_empty()).then(|| vec![request.capabilities.clone()]),
+            options: (!request.options.is_empty()).then(|| request.options.clone()),
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use bollard::models::{ContainerSummary, ContainerSummaryStateEnum, ImageSummary, SystemDataUsageResponse, Volume};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result};
+
+use crate::format::format_bytes;
+
+/// Disk space used by a single image, container or volume
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiskUsageEntry {
+    /// Image tag, container name or volume name (falling back to the ID)
+    pub name: String,
+    /// Disk space used in bytes
+    pub size_bytes: u64,
+    /// Whether the resource is in use (image used by a container, container running, volume mounted)
+    pub in_use: bool,
+}
+
+impl DiskUsageEntry {
+    /// Returns true if the resource is unused and its space could be reclaimed by pruning
+    #[must_use]
+    pub const fn is_reclaimable(&self) -> bool {
+        !self.in_use
+    }
+}
+
+impl From<&ImageSummary> for DiskUsageEntry {
+    fn from(image: &ImageSummary) -> Self {
+        Self {
+            name: image.repo_tags.first().unwrap_or(&image.id).clone(),
+            size_bytes: image.size.max(0) as u64,
+            in_use: image.containers > 0,
+        }
+    }
+}
+
+impl From<&ContainerSummary> for DiskUsageEntry {
+    fn from(container: &ContainerSummary) -> Self {
+        Self {
+            name: container
+                .names
+                .as_ref()
+                .and_then(|names| names.first())
+                .or(container.id.as_ref())
+                .map_or_else(String::new, |name| name.trim_start_matches('/').to_string()),
+            size_bytes: container.size_rw.unwrap_or(0).max(0) as u64,
+            in_use: container.state == Some(ContainerSummaryStateEnum::RUNNING),
+        }
+    }
+}
+
+impl From<&Volume> for DiskUsageEntry {
+    fn from(volume: &Volume) -> Self {
+        // Docker reports -1 for sizes and reference counts it hasn't calculated
+        let (size, ref_count) = volume
+            .usage_data
+            .as_ref()
+            .map_or((0, 0), |usage| (usage.size, usage.ref_count));
+        Self {
+            name: volume.name.clone(),
+            size_bytes: size.max(0) as u64,
+            in_use: ref_count > 0,
+        }
+    }
+}
+
+/// Disk space used by Docker images, containers and volumes, as reported by `docker system df`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiskUsageReport {
+    /// Disk usage of each image
+    pub images: Vec<DiskUsageEntry>,
+    /// Disk usage of each container's writable layer
+    pub containers: Vec<DiskUsageEntry>,
+    /// Disk usage of each volume
+    pub volumes: Vec<DiskUsageEntry>,
+}
+
+impl DiskUsageReport {
+    /// Total disk space used by images in bytes
+    #[must_use]
+    pub fn images_bytes(&self) -> u64 {
+        total(&self.images, |_| true)
+    }
+
+    /// Total disk space used by containers in bytes
+    #[must_use]
+    pub fn containers_bytes(&self) -> u64 {
+        total(&self.containers, |_| true)
+    }
+
+    /// Total disk space used by volumes in bytes
+    #[must_use]
+    pub fn volumes_bytes(&self) -> u64 {
+        total(&self.volumes, |_| true)
+    }
+
+    /// Total disk space used by images, containers and volumes in bytes
+    #[must_use]
+    pub fn total_bytes(&self) -> u64 {
+        self.images_bytes() + self.containers_bytes() + self.volumes_bytes()
+    }
+
+    /// Disk space in bytes that could be freed by removing unused images, stopped containers and unused volumes
+    #[must_use]
+    pub fn reclaimable_bytes(&self) -> u64 {
+        total(&self.images, DiskUsageEntry::is_reclaimable)
+            + total(&self.containers, DiskUsageEntry::is_reclaimable)
+            + total(&self.volumes, DiskUsageEntry::is_reclaimable)
+    }
+}
+
+impl From<SystemDataUsageResponse> for DiskUsageReport {
+    fn from(usage: SystemDataUsageResponse) -> Self {
+        Self {
+            images: usage.images.unwrap_or_default().iter().map(DiskUsageEntry::from).collect(),
+            containers: usage
+                .containers
+                .unwrap_or_default()
+                .iter()
+                .map(DiskUsageEntry::from)
+                .collect(),
+            volumes: usage.volumes.unwrap_or_default().iter().map(DiskUsageEntry::from).collect(),
+        }
+    }
+}
+
+impl Display for DiskUsageReport {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        for (title, entries) in [
+            ("Images", &self.images),
+            ("Containers", &self.containers),
+            ("Volumes", &self.volumes),
+        ] {
+            writeln!(
+                fmt,
+                "{title}: {} ({} reclaimable)",
+                format_bytes(total(entries, |_| true)),
+                format_bytes(total(entries, DiskUsageEntry::is_reclaimable))
+            )?;
+            for entry in entries {
+                let marker = if entry.is_reclaimable() { " [unused]" } else { "" };
+                writeln!(fmt, "  {} {}{marker}", entry.name, format_bytes(entry.size_bytes))?;
+            }
+        }
+        write!(
+            fmt,
+            "Total: {} ({} reclaimable)",
+            format_bytes(self.total_bytes()),
+            format_bytes(self.reclaimable_bytes())
+        )
+    }
+}
+
+/// Sums the sizes of the entries matching a predicate.
+fn total<F: Fn(&DiskUsageEntry) -> bool>(entries: &[DiskUsageEntry], predicate: F) -> u64 {
+    entries
+        .iter()
+        .filter(|entry| predicate(entry))
+        .map(|entry| entry.size_bytes)
+        .sum()
+}
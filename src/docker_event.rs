@@ -0,0 +1,63 @@
+use bollard::models::EventMessage;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Result},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// A container lifecycle event reported by the Docker daemon
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DockerEvent {
+    /// What happened to the container (e.g., "create", "start", "die", "stop", "destroy")
+    pub action: String,
+    /// ID of the container the event relates to
+    pub container_id: String,
+    /// Name of the container the event relates to, if reported
+    pub container_name: Option<String>,
+    /// Exit code of the container's main process (only reported for "die" events)
+    pub exit_code: Option<i64>,
+    /// All attributes reported with the event, including the container's labels
+    pub attributes: HashMap<String, String>,
+    /// Time the event occurred
+    pub timestamp: SystemTime,
+}
+
+impl From<EventMessage> for DockerEvent {
+    fn from(event: EventMessage) -> Self {
+        let (container_id, attributes) = event
+            .actor
+            .map(|actor| (actor.id.unwrap_or_default(), actor.attributes.unwrap_or_default()))
+            .unwrap_or_default();
+
+        // Prefer the nanosecond timestamp, falling back to whole seconds
+        let timestamp = event.time_nano.map_or_else(
+            || UNIX_EPOCH + Duration::from_secs(event.time.unwrap_or(0).max(0) as u64),
+            |nanos| UNIX_EPOCH + Duration::from_nanos(nanos.max(0) as u64),
+        );
+
+        Self {
+            action: event.action.unwrap_or_default(),
+            container_id,
+            container_name: attributes.get("name").cloned(),
+            exit_code: attributes.get("exitCode").and_then(|code| code.parse().ok()),
+            attributes,
+            timestamp,
+        }
+    }
+}
+
+impl Display for DockerEvent {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        write!(
+            fmt,
+            "{} {}",
+            self.container_name.as_ref().unwrap_or(&self.container_id),
+            self.action
+        )?;
+        if let Some(code) = self.exit_code {
+            write!(fmt, " (exit code {code})")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use bollard::models::SystemInfo;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result};
+
+use crate::format::format_bytes;
+
+/// System-wide information reported by the Docker daemon
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DockerInfo {
+    /// Version of the Docker server (e.g., "28.0.4")
+    pub server_version: String,
+    /// Kernel version of the Docker host
+    pub kernel_version: String,
+    /// Total memory available to the Docker host in bytes
+    pub total_memory: u64,
+    /// Number of CPUs available to the Docker host
+    pub num_cpus: u32,
+    /// Storage driver in use (e.g., "overlay2")
+    pub storage_driver: String,
+    /// Default container runtime (e.g., "runc")
+    pub runtime: String,
+    /// Name of the host operating system (e.g., "Docker Desktop", "Ubuntu 24.04 LTS")
+    pub operating_system: String,
+    /// Hardware architecture of the Docker host (e.g., `x86_64`, `aarch64`)
+    pub architecture: String,
+    /// Current time on the Docker host in RFC 3339 format, useful for spotting clock skew
+    pub system_time: String,
+}
+
+impl From<SystemInfo> for DockerInfo {
+    fn from(info: SystemInfo) -> Self {
+        let unknown = || "unknown".to_string();
+        Self {
+            server_version: info.server_version.unwrap_or_else(unknown),
+            kernel_version: info.kernel_version.unwrap_or_else(unknown),
+            total_memory: info.mem_total.unwrap_or(0).max(0) as u64,
+            num_cpus: u32::try_from(info.ncpu.unwrap_or(0).max(0)).unwrap_or(u32::MAX),
+            storage_driver: info.driver.unwrap_or_else(unknown),
+            runtime: info.default_runtime.unwrap_or_else(unknown),
+            operating_system: info.operating_system.unwrap_or_else(unknown),
+            architecture: info.architecture.unwrap_or_else(unknown),
+            system_time: info.system_time.unwrap_or_else(unknown),
+        }
+    }
+}
+
+impl Display for DockerInfo {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        write!(
+            fmt,
+            "Server Version: {}\nKernel Version: {}\nOperating System: {}\nArchitecture: {}\nCPUs: {}\nTotal Memory: {}\nStorage Driver: {}\nRuntime: {}\nSystem Time: {}",
+            self.server_version,
+            self.kernel_version,
+            self.operating_system,
+            self.architecture,
+            self.num_cpus,
+            format_bytes(self.total_memory),
+            self.storage_driver,
+            self.runtime,
+            self.system_time
+        )
+    }
+}
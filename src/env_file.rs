@@ -0,0 +1,36 @@
+/// Parses the contents of an environment file into `(name, value)` pairs, in file order.
+///
+/// Each line is `KEY=VALUE`, optionally prefixed with `export `. Blank lines and lines starting with `#`
+/// are skipped, and a value wrapped in matching single or double quotes has the quotes removed.
+///
+/// # Errors
+/// Returns a message naming the line number of the first line that is not a valid `KEY=VALUE` pair.
+pub fn parse_env_file(contents: &str) -> Result<Vec<(String, String)>, String> {
+    let mut vars = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let assignment = line.strip_prefix("export ").map_or(line, str::trim_start);
+        let Some((name, value)) = assignment.split_once('=') else {
+            return Err(format!("line {}: expected KEY=VALUE", index + 1));
+        };
+        let name = name.trim();
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            return Err(format!("line {}: invalid variable name '{name}'", index + 1));
+        }
+
+        vars.push((name.to_string(), unquote(value.trim()).to_string()));
+    }
+    Ok(vars)
+}
+
+/// Removes one pair of matching single or double quotes surrounding a value.
+fn unquote(value: &str) -> &str {
+    ['"', '\'']
+        .into_iter()
+        .find_map(|quote| value.strip_prefix(quote).and_then(|inner| inner.strip_suffix(quote)))
+        .unwrap_or(value)
+}
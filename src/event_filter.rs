@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Criteria for selecting which container events to receive from the Docker daemon
+///
+/// An empty filter matches every container event.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventFilter {
+    /// Labels the container must carry, with the value they must have (any value if `None`)
+    pub labels: HashMap<String, Option<String>>,
+    /// Names or IDs of the containers to report events for (all containers if empty)
+    pub containers: Vec<String>,
+    /// Actions to report, e.g. "start" or "die" (all actions if empty)
+    pub actions: Vec<String>,
+}
+
+impl EventFilter {
+    /// Create a new `EventFilter` matching every container event
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only report events for containers with the given label set to the given value
+    #[must_use]
+    pub fn label<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        let _unused = self.labels.insert(key.into(), Some(value.into()));
+        self
+    }
+
+    /// Only report events for containers carrying the given label, whatever its value
+    #[must_use]
+    pub fn has_label<K: Into<String>>(mut self, key: K) -> Self {
+        let _unused = self.labels.insert(key.into(), None);
+        self
+    }
+
+    /// Also report events for the given container name or ID
+    #[must_use]
+    pub fn container<S: Into<String>>(mut self, container: S) -> Self {
+        self.containers.push(container.into());
+        self
+    }
+
+    /// Also report events with the given action, e.g. "die"
+    #[must_use]
+    pub fn action<S: Into<String>>(mut self, action: S) -> Self {
+        self.actions.push(action.into());
+        self
+    }
+
+    /// Returns the filter in the form expected by the Docker events API
+    #[must_use]
+    pub fn to_docker_filters(&self) -> HashMap<String, Vec<String>> {
+        let mut filters = HashMap::new();
+        let _unused = filters.insert("type".to_string(), vec!["container".to_string()]);
+        if !self.labels.is_empty() {
+            let labels = self
+                .labels
+                .iter()
+                .map(|(key, value)| value.as_ref().map_or_else(|| key.clone(), |value| format!("{key}={value}")))
+                .collect();
+            let _unused = filters.insert("label".to_string(), labels);
+        }
+        if !self.containers.is_empty() {
+            let _unused = filters.insert("container".to_string(), self.containers.clone());
+        }
+        if !self.actions.is_empty() {
+            let _unused = filters.insert("event".to_string(), self.actions.clone());
+        }
+        filters
+    }
+}
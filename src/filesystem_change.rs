@@ -0,0 +1,30 @@
+use bollard::models::FilesystemChange as DockerFilesystemChange;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result};
+
+use crate::change_kind::ChangeKind;
+
+/// A path in a container's filesystem that differs from the container's image
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FilesystemChange {
+    /// Absolute path of the changed file or directory
+    pub path: String,
+    /// How the path changed
+    pub kind: ChangeKind,
+}
+
+impl From<DockerFilesystemChange> for FilesystemChange {
+    fn from(change: DockerFilesystemChange) -> Self {
+        Self {
+            path: change.path,
+            kind: ChangeKind::from(change.kind),
+        }
+    }
+}
+
+impl Display for FilesystemChange {
+    /// Formats the change as `docker diff` does, e.g. "A /tmp/output.log"
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        write!(fmt, "{} {}", self.kind, self.path)
+    }
+}
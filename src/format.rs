@@ -1,25 +1,36 @@
-use std::time::Duration;
+use chrono::DateTime;
+use std::time::{Duration, SystemTime};
+
+use crate::byte_format::ByteFormat;
 
 /// Format bytes in human readable format
 pub fn format_bytes(bytes: u64) -> String {
-    const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
+    format_bytes_opts(bytes, ByteFormat::default())
+}
 
-    if bytes == 0 {
-        return "0 B".to_string();
-    }
+/// Format bytes in human readable format, with the given units and precision
+///
+/// Sizes under a kilobyte are shown as whole bytes. Larger sizes are shown in the largest unit (up to
+/// terabytes) that is no larger than the size, rounded to the format's precision, as `format_bytes` always
+/// has. A size just under a unit can therefore round up to the base, e.g. 1,048,575 bytes is "1024.0 KB"
+/// and 999,999 bytes in SI units is "1000.0 kB".
+#[must_use]
+pub fn format_bytes_opts(bytes: u64, format: ByteFormat) -> String {
+    let labels = format.unit_labels();
+    let base = format.multiplier() as f64;
+    let precision = usize::from(format.precision);
 
     let mut size = bytes as f64;
     let mut unit_index = 0;
-
-    while size >= 1024.0 && unit_index < UNITS.len() - 1 {
-        size /= 1024.0;
+    while unit_index < labels.len() - 1 && size >= base {
+        size /= base;
         unit_index += 1;
     }
 
     if unit_index == 0 {
-        format!("{bytes} {}", UNITS[unit_index])
+        format!("{bytes} {}", labels[unit_index])
     } else {
-        format!("{size:.1} {}", UNITS[unit_index])
+        format!("{size:.precision$} {}", labels[unit_index])
     }
 }
 
@@ -55,3 +66,246 @@ pub fn format_duration(duration: Duration) -> String {
         }
     }
 }
+
+/// Parse a human readable size such as "512m", "1.5 GB" or "2GiB" into bytes
+///
+/// Units are `b`, `k`, `m`, `g` and `t`, optionally followed by `b` or `ib`, and are powers of 1024 as
+/// in Docker's memory limits and `format_bytes`, so "1k", "1KB" and "1KiB" are all 1024 bytes. A number
+/// without a unit is in bytes. Use `parse_bytes_si` for powers of 1000.
+///
+/// `format_bytes` rounds, so parsing its output gives the original size only to within half of the last
+/// decimal place shown, e.g. "1.5 KB" is 1536 bytes whether it was formatted from 1510 or 1560.
+///
+/// # Errors
+/// Returns a message if the size is empty, negative, has an unknown unit, or does not fit in a `u64`.
+pub fn parse_bytes(size: &str) -> Result<u64, String> {
+    parse_size(size, 1024)
+}
+
+/// Parse a human readable size such as "500MB" into bytes, with SI units
+///
+/// As `parse_bytes`, except that `k`, `m`, `g` and `t` (with or without a trailing `b`) are powers of
+/// 1000, so "1kB" is 1000 bytes, while the binary units `kib`, `mib`, `gib` and `tib` remain powers of 1024.
+///
+/// # Errors
+/// Returns a message if the size is empty, negative, has an unknown unit, or does not fit in a `u64`.
+pub fn parse_bytes_si(size: &str) -> Result<u64, String> {
+    parse_size(size, 1000)
+}
+
+/// Parse a human readable duration such as "1h30m" or "45s", using the units of `format_duration`
+///
+/// The duration is a sequence of whole numbers, each followed by a unit of `d`, `h`, `m` or `s`.
+///
+/// `format_duration` only shows its two largest units, truncating the rest, so parsing its output can
+/// give a shorter duration than was formatted, e.g. 3661 seconds formats as "1h1m", which parses as
+/// 3660 seconds. Formatting the parsed duration again gives the same text.
+///
+/// # Errors
+/// Returns a message if the duration is empty, negative, has a missing or unknown unit, or overflows.
+pub fn parse_duration(duration: &str) -> Result<Duration, String> {
+    let input = duration.trim().to_ascii_lowercase();
+    if input.is_empty() {
+        return Err("duration must not be empty".to_string());
+    }
+    if input.starts_with('-') {
+        return Err(format!("duration '{duration}' must not be negative"));
+    }
+
+    let mut total_seconds: u64 = 0;
+    let mut rest = input.as_str();
+    while !rest.is_empty() {
+        let digits = rest.find(|chr: char| !chr.is_ascii_digit()).unwrap_or(rest.len());
+        if digits == 0 {
+            return Err(format!(
+                "duration '{duration}' must be numbers followed by units, e.g. '1h30m'"
+            ));
+        }
+        let value: u64 = rest[..digits]
+            .parse()
+            .map_err(|_err| format!("duration '{duration}' is too large"))?;
+        let Some(unit) = rest[digits..].chars().next() else {
+            return Err(format!("duration '{duration}' is missing a unit after '{value}'"));
+        };
+        let unit_seconds = match unit {
+            'd' => 86400,
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return Err(format!("duration '{duration}' has unknown unit '{unit}'")),
+        };
+        total_seconds = value
+            .checked_mul(unit_seconds)
+            .and_then(|seconds| total_seconds.checked_add(seconds))
+            .ok_or_else(|| format!("duration '{duration}' is too large"))?;
+        rest = &rest[digits + unit.len_utf8()..];
+    }
+    Ok(Duration::from_secs(total_seconds))
+}
+
+/// Parses a Docker RFC 3339 timestamp, such as a container's `created` or `finished_at` time.
+///
+/// Returns `None` if the timestamp cannot be parsed or is Docker's zero time ("0001-01-01T00:00:00Z"),
+/// which it reports for events that have not happened, e.g. a container that has never finished.
+pub fn parse_docker_time(timestamp: &str) -> Option<SystemTime> {
+    DateTime::parse_from_rfc3339(timestamp)
+        .ok()
+        .filter(|time| time.timestamp() > 0)
+        .map(SystemTime::from)
+}
+
+/// Parse a human readable size, with `k`, `m`, `g` and `t` as powers of `base`.
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "Fractional sizes are rounded and checked to fit in a `u64` before the cast."
+)]
+fn parse_size(size: &str, base: u64) -> Result<u64, String> {
+    let input = size.trim().to_ascii_lowercase();
+    if input.is_empty() {
+        return Err("size must not be empty".to_string());
+    }
+    if input.starts_with('-') {
+        return Err(format!("size '{size}' must not be negative"));
+    }
+
+    let split = input
+        .find(|chr: char| !chr.is_ascii_digit() && chr != '.')
+        .unwrap_or(input.len());
+    let (number, unit) = input.split_at(split);
+    let (prefix, suffix) = unit.trim_start().split_at(unit.trim_start().len().min(1));
+    let exponent = match prefix {
+        "" | "b" if suffix.is_empty() => 0,
+        "k" => 1,
+        "m" => 2,
+        "g" => 3,
+        "t" => 4,
+        _ => return Err(format!("size '{size}' has unknown unit '{}'", unit.trim_start())),
+    };
+    let multiplier = match suffix {
+        "" | "b" => base.pow(exponent),
+        "ib" => 1024_u64.pow(exponent),
+        _ => return Err(format!("size '{size}' has unknown unit '{}'", unit.trim_start())),
+    };
+
+    if let Ok(value) = number.parse::<u64>() {
+        return value
+            .checked_mul(multiplier)
+            .ok_or_else(|| format!("size '{size}' is too large"));
+    }
+    let value: f64 = number
+        .parse()
+        .map_err(|_err| format!("size '{size}' must start with a number, e.g. '512m'"))?;
+    let bytes = (value * multiplier as f64).round();
+    if bytes >= u64::MAX as f64 {
+        return Err(format!("size '{size}' is too large"));
+    }
+    Ok(bytes as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic pseudo-random values spread over every magnitude up to `max`
+    fn sample_values(max: u64) -> impl Iterator<Item = u64> {
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        (0..20_000).map(move |index| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let magnitude = max >> (index % 64);
+            if magnitude == 0 { state % 4096 } else { state % magnitude }
+        })
+    }
+
+    /// Largest difference between a size and its formatted text: half of the last decimal place shown
+    fn rounding_bound(bytes: u64, format: ByteFormat) -> u64 {
+        let base = format.multiplier();
+        let mut unit = 1;
+        while unit < base.pow(4) && bytes >= unit * base {
+            unit *= base;
+        }
+        unit / (2 * 10_u64.pow(u32::from(format.precision))) + 1
+    }
+
+    #[test]
+    fn parse_bytes_round_trips_format_bytes() {
+        for bytes in sample_values(u64::MAX / 2) {
+            let formatted = format_bytes(bytes);
+            let parsed = parse_bytes(&formatted).unwrap_or_else(|err| panic!("{formatted}: {err}"));
+            assert!(
+                parsed.abs_diff(bytes) <= rounding_bound(bytes, ByteFormat::default()),
+                "{bytes} formatted as {formatted} parsed as {parsed}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_bytes_round_trips_iec_and_si_formats() {
+        for format in [ByteFormat::iec(), ByteFormat::si(), ByteFormat::iec().with_precision(3)] {
+            for bytes in sample_values(u64::MAX / 2) {
+                let formatted = format_bytes_opts(bytes, format);
+                let parsed = if format == ByteFormat::si() {
+                    parse_bytes_si(&formatted)
+                } else {
+                    parse_bytes(&formatted)
+                }
+                .unwrap_or_else(|err| panic!("{formatted}: {err}"));
+                assert!(
+                    parsed.abs_diff(bytes) <= rounding_bound(bytes, format),
+                    "{bytes} formatted as {formatted} parsed as {parsed}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn parse_duration_round_trips_format_duration() {
+        for seconds in sample_values(u64::MAX / 86400) {
+            let duration = Duration::from_secs(seconds);
+            let formatted = format_duration(duration);
+            let parsed = parse_duration(&formatted).unwrap_or_else(|err| panic!("{formatted}: {err}"));
+            assert_eq!(format_duration(parsed), formatted, "{seconds}s parsed as {parsed:?}");
+
+            // Only the units below the two largest shown are lost
+            let lost = duration.saturating_sub(parsed).as_secs();
+            let bound = match seconds {
+                0..3600 => 0,
+                3600..86400 => 59,
+                _ => 3599,
+            };
+            assert!(
+                parsed <= duration && lost <= bound,
+                "{seconds}s formatted as {formatted} parsed as {parsed:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn format_bytes_unit_boundaries() {
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1024), "1.0 KB");
+        assert_eq!(format_bytes(999_999), "976.6 KB");
+        assert_eq!(format_bytes(1_048_575), "1024.0 KB");
+        assert_eq!(format_bytes(1_048_576), "1.0 MB");
+        assert_eq!(format_bytes_opts(1024, ByteFormat::iec()), "1.0 KiB");
+        assert_eq!(format_bytes_opts(999_999, ByteFormat::iec().with_precision(2)), "976.56 KiB");
+    }
+
+    #[test]
+    fn format_bytes_si_unit_boundaries() {
+        assert_eq!(format_bytes_opts(999, ByteFormat::si()), "999 B");
+        assert_eq!(format_bytes_opts(1000, ByteFormat::si()), "1.0 kB");
+        assert_eq!(format_bytes_opts(1023, ByteFormat::si()), "1.0 kB");
+        assert_eq!(format_bytes_opts(1024, ByteFormat::si()), "1.0 kB");
+        assert_eq!(format_bytes_opts(999_999, ByteFormat::si()), "1000.0 kB");
+        assert_eq!(format_bytes_opts(999_999, ByteFormat::si().with_precision(3)), "999.999 kB");
+        assert_eq!(format_bytes_opts(1_000_000, ByteFormat::si()), "1.0 MB");
+    }
+
+    #[test]
+    fn format_duration_is_lossy() {
+        let duration = parse_duration(&format_duration(Duration::from_secs(3661)));
+        assert_eq!(duration, Ok(Duration::from_mins(61)));
+    }
+}
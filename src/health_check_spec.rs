@@ -0,0 +1,55 @@
+use bollard::models::HealthConfig;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Health check to configure on a container at creation time, overriding any `HEALTHCHECK` baked into the image
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HealthCheckSpec {
+    /// Test to perform, e.g. `["CMD", "curl", "-f", "http://localhost/"]` or `["CMD-SHELL", "pg_isready"]`
+    pub test: Vec<String>,
+    /// Time between checks (Docker default if unset)
+    pub interval: Option<Duration>,
+    /// Time to wait before considering a check to have hung (Docker default if unset)
+    pub timeout: Option<Duration>,
+    /// Consecutive failures needed to consider the container unhealthy (Docker default if unset)
+    pub retries: Option<u32>,
+    /// Initialisation period during which failures don't count towards the retries (Docker default if unset)
+    pub start_period: Option<Duration>,
+}
+
+impl HealthCheckSpec {
+    /// Creates a health check that runs the given test with Docker's default timings
+    pub fn new<I: IntoIterator<Item = S>, S: Into<String>>(test: I) -> Self {
+        Self {
+            test: test.into_iter().map(Into::into).collect(),
+            interval: None,
+            timeout: None,
+            retries: None,
+            start_period: None,
+        }
+    }
+
+    /// Creates a health check that executes a command directly (`CMD`)
+    pub fn cmd<I: IntoIterator<Item = S>, S: Into<String>>(args: I) -> Self {
+        Self::new(std::iter::once("CMD".to_string()).chain(args.into_iter().map(Into::into)))
+    }
+
+    /// Creates a health check that runs a command with the container's default shell (`CMD-SHELL`)
+    pub fn cmd_shell<S: Into<String>>(command: S) -> Self {
+        Self::new(["CMD-SHELL".to_string(), command.into()])
+    }
+}
+
+impl From<&HealthCheckSpec> for HealthConfig {
+    fn from(spec: &HealthCheckSpec) -> Self {
+        let nanos = |duration: Duration| i64::try_from(duration.as_nanos()).unwrap_or(i64::MAX);
+        Self {
+            test: Some(spec.test.clone()),
+            interval: spec.interval.map(nanos),
+            timeout: spec.timeout.map(nanos),
+            retries: spec.retries.map(i64::from),
+            start_period: spec.start_period.map(nanos),
+            start_interval: None,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Criteria for selecting which images to list from the Docker daemon
+///
+/// An empty filter matches every top-level image.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageFilter {
+    /// Only match untagged images if `true`, or only tagged images if `false` (either if `None`)
+    pub dangling: Option<bool>,
+    /// Labels the image must carry, with the value they must have (any value if `None`)
+    pub labels: HashMap<String, Option<String>>,
+    /// Reference patterns the image must match one of, e.g. "myapp/*" or "nginx:1.*" (any if empty)
+    pub references: Vec<String>,
+}
+
+impl ImageFilter {
+    /// Create a new `ImageFilter` matching every top-level image
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only match untagged (dangling) images if `true`, or only tagged images if `false`
+    #[must_use]
+    pub const fn dangling(mut self, dangling: bool) -> Self {
+        self.dangling = Some(dangling);
+        self
+    }
+
+    /// Only match images with the given label set to the given value
+    #[must_use]
+    pub fn label<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        let _unused = self.labels.insert(key.into(), Some(value.into()));
+        self
+    }
+
+    /// Only match images carrying the given label, whatever its value
+    #[must_use]
+    pub fn has_label<K: Into<String>>(mut self, key: K) -> Self {
+        let _unused = self.labels.insert(key.into(), None);
+        self
+    }
+
+    /// Also match images whose reference matches the given pattern, e.g. "myapp/*"
+    #[must_use]
+    pub fn reference<S: Into<String>>(mut self, pattern: S) -> Self {
+        self.references.push(pattern.into());
+        self
+    }
+
+    /// Returns the filter in the form expected by the Docker image list API
+    #[must_use]
+    pub fn to_docker_filters(&self) -> HashMap<String, Vec<String>> {
+        let mut filters = HashMap::new();
+        if let Some(dangling) = self.dangling {
+            let _unused = filters.insert("dangling".to_string(), vec![dangling.to_string()]);
+        }
+        if !self.labels.is_empty() {
+            let labels = self
+                .labels
+                .iter()
+                .map(|(key, value)| value.as_ref().map_or_else(|| key.clone(), |value| format!("{key}={value}")))
+                .collect();
+            let _unused = filters.insert("label".to_string(), labels);
+        }
+        if !self.references.is_empty() {
+            let _unused = filters.insert("reference".to_string(), self.references.clone());
+        }
+        filters
+    }
+}
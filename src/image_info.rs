@@ -0,0 +1,54 @@
+use bollard::models::ImageSummary;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Display, Formatter, Result},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::format::format_bytes;
+
+/// Summary of a locally available Docker image
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageInfo {
+    /// Image ID (e.g., "sha256:...")
+    pub id: String,
+    /// Repository tags referencing the image (e.g., "nginx:latest")
+    pub tags: Vec<String>,
+    /// Repository digests referencing the image (e.g., "nginx@sha256:...")
+    pub digests: Vec<String>,
+    /// Total size of the image on disk in bytes
+    pub size_bytes: u64,
+    /// Time the image was created
+    pub created: SystemTime,
+}
+
+impl ImageInfo {
+    /// Get formatted image size string
+    #[must_use]
+    pub fn size_display(&self) -> String {
+        format_bytes(self.size_bytes)
+    }
+}
+
+impl From<ImageSummary> for ImageInfo {
+    fn from(summary: ImageSummary) -> Self {
+        Self {
+            id: summary.id,
+            tags: summary.repo_tags,
+            digests: summary.repo_digests,
+            size_bytes: summary.size.max(0) as u64,
+            created: UNIX_EPOCH + Duration::from_secs(summary.created.max(0) as u64),
+        }
+    }
+}
+
+impl Display for ImageInfo {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        let tags = if self.tags.is_empty() {
+            "<none>".to_string()
+        } else {
+            self.tags.join(", ")
+        };
+        write!(fmt, "{} [{}] {}", self.id, tags, self.size_display())
+    }
+}
@@ -74,30 +74,109 @@
     reason = "Multiple versions of some dependencies are used in the workspace, but they are compatible and do not cause issues."
 )]
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
 #[cfg(feature = "aws_ecr")]
 mod credentials;
+#[cfg(feature = "test-util")]
+mod mock_call;
+#[cfg(feature = "test-util")]
+mod mock_engine;
 
 mod anchor_error;
+mod bind_propagation;
+mod byte_format;
+mod change_kind;
 mod client;
+mod client_builder;
+mod container_engine;
+mod container_filter;
+mod container_inspect;
 mod container_metrics;
+mod container_signal;
+mod container_spec;
+mod container_stats;
+mod daemon_version;
+mod device_mapping;
+mod device_request;
+mod disk_usage;
+mod docker_event;
+mod docker_info;
+mod env_file;
+mod event_filter;
+mod filesystem_change;
 mod format;
+mod health_check_spec;
 mod health_status;
+mod image_filter;
+mod image_info;
+mod log_entry;
+mod log_line_buffer;
+mod mount_info;
+mod mount_parse_error;
 mod mount_type;
+mod network_mode;
+mod platform;
+mod port_mapping;
+mod process_info;
+mod pull_outcome;
+mod pull_progress;
+mod resource_kind;
 mod resource_status;
+mod selinux_label;
 mod start_docker_daemon;
+mod validation;
+mod volume_info;
 
 /// Re-export the main types and traits for easy access
 pub mod prelude {
     #[cfg(feature = "aws_ecr")]
     pub use crate::credentials::get_ecr_credentials;
+    #[cfg(feature = "test-util")]
+    pub use crate::{mock_call::MockCall, mock_engine::MockEngine};
 
     pub use crate::{
-        anchor_error::{AnchorError, AnchorResult},
+        anchor_error::{AnchorError, AnchorResult, ErrorSource},
+        bind_propagation::BindPropagation,
+        byte_format::{ByteBase, ByteFormat},
+        change_kind::ChangeKind,
         client::Client,
+        client_builder::ClientBuilder,
+        container_engine::ContainerEngine,
+        container_filter::ContainerFilter,
+        container_inspect::ContainerInspect,
         container_metrics::ContainerMetrics,
+        container_signal::ContainerSignal,
+        container_spec::ContainerSpec,
+        container_stats::ContainerStats,
+        daemon_version::DaemonVersion,
+        device_mapping::DeviceMapping,
+        device_request::DeviceRequest,
+        disk_usage::{DiskUsageEntry, DiskUsageReport},
+        docker_event::DockerEvent,
+        docker_info::DockerInfo,
+        event_filter::EventFilter,
+        filesystem_change::FilesystemChange,
+        format::{format_bytes_opts, parse_bytes, parse_bytes_si, parse_duration},
+        health_check_spec::HealthCheckSpec,
         health_status::HealthStatus,
+        image_filter::ImageFilter,
+        image_info::ImageInfo,
+        log_entry::{LogEntry, LogStream},
+        mount_info::MountInfo,
+        mount_parse_error::MountParseError,
         mount_type::MountType,
+        network_mode::NetworkMode,
+        platform::Platform,
+        port_mapping::{PortMapping, Protocol},
+        process_info::ProcessInfo,
+        pull_outcome::PullOutcome,
+        pull_progress::{ProgressDetail, PullProgress},
+        resource_kind::ResourceKind,
         resource_status::ResourceStatus,
-        start_docker_daemon::start_docker_daemon,
+        selinux_label::SeLinuxLabel,
+        start_docker_daemon::{start_docker_daemon, start_docker_daemon_and_wait},
+        validation::{is_valid_capability, is_valid_container_name, is_valid_hostname, is_valid_image_reference},
+        volume_info::VolumeInfo,
     };
 }
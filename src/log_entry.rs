@@ -0,0 +1,88 @@
+use bollard::container::LogOutput;
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Display, Formatter, Result},
+    time::SystemTime,
+};
+
+/// Output stream a container log line was written to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LogStream {
+    /// Standard output
+    Stdout,
+    /// Standard error
+    Stderr,
+    /// Standard input
+    Stdin,
+    /// Combined output of a container with a TTY attached
+    Console,
+}
+
+impl Display for LogStream {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Stdout => write!(fmt, "stdout"),
+            Self::Stderr => write!(fmt, "stderr"),
+            Self::Stdin => write!(fmt, "stdin"),
+            Self::Console => write!(fmt, "console"),
+        }
+    }
+}
+
+/// A single line of container log output
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogEntry {
+    /// Stream the line was written to
+    pub stream: LogStream,
+    /// Time the line was written, if Docker reported it
+    pub timestamp: Option<SystemTime>,
+    /// Content of the line, without the trailing newline
+    pub message: String,
+}
+
+impl From<&LogOutput> for LogStream {
+    fn from(output: &LogOutput) -> Self {
+        match output {
+            LogOutput::StdOut { .. } => Self::Stdout,
+            LogOutput::StdErr { .. } => Self::Stderr,
+            LogOutput::StdIn { .. } => Self::Stdin,
+            LogOutput::Console { .. } => Self::Console,
+        }
+    }
+}
+
+impl LogEntry {
+    /// Splits a chunk of Docker log output into entries, one per line.
+    ///
+    /// Lines prefixed with an RFC 3339 timestamp (as returned when timestamps are requested) have it
+    /// parsed into `timestamp` and removed from `message`. A line Docker splits across chunks becomes two
+    /// entries, so reassemble whole lines first when reading a log stream.
+    #[must_use]
+    pub fn parse(output: &LogOutput) -> Vec<Self> {
+        let stream = LogStream::from(output);
+        output.to_string().lines().map(|line| Self::from_line(stream, line)).collect()
+    }
+
+    /// Creates an entry from a single line of a stream's output, parsing any leading RFC 3339 timestamp
+    #[must_use]
+    pub fn from_line(stream: LogStream, line: &str) -> Self {
+        let timestamped = line.split_once(' ').and_then(|(timestamp, message)| {
+            DateTime::parse_from_rfc3339(timestamp)
+                .ok()
+                .map(|timestamp| (SystemTime::from(timestamp), message))
+        });
+        let (timestamp, message) = timestamped.map_or((None, line), |(timestamp, message)| (Some(timestamp), message));
+        Self {
+            stream,
+            timestamp,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl Display for LogEntry {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        write!(fmt, "[{}] {}", self.stream, self.message)
+    }
+}
@@ -0,0 +1,104 @@
+use bollard::container::LogOutput;
+use std::collections::HashMap;
+
+use crate::log_entry::LogStream;
+
+/// Reassembles complete log lines from Docker's log frames.
+///
+/// Docker can split a line across frames, so the unfinished end of each stream's output is held back
+/// until the rest of the line arrives. Each stream is buffered separately, so a partial stdout line is
+/// never joined onto stderr output. Bytes are buffered before decoding, keeping split characters intact.
+#[derive(Debug, Default)]
+pub struct LogLineBuffer {
+    /// Unfinished trailing line of each stream
+    partial: HashMap<LogStream, Vec<u8>>,
+}
+
+impl LogLineBuffer {
+    /// Adds a frame of log output, returning the lines it completes, without their line endings
+    pub fn push(&mut self, output: LogOutput) -> Vec<(LogStream, String)> {
+        let stream = LogStream::from(&output);
+        let partial = self.partial.entry(stream).or_default();
+        partial.extend_from_slice(&output.into_bytes());
+
+        let mut lines = Vec::new();
+        while let Some(end) = partial.iter().position(|&byte| byte == b'\n') {
+            let line: Vec<u8> = partial.drain(..=end).collect();
+            lines.push((stream, decode_line(&line)));
+        }
+        lines
+    }
+
+    /// Returns the unfinished lines left once the log stream has ended
+    pub fn finish(self) -> Vec<(LogStream, String)> {
+        self.partial
+            .into_iter()
+            .filter(|(_, partial)| !partial.is_empty())
+            .map(|(stream, partial)| (stream, decode_line(&partial)))
+            .collect()
+    }
+}
+
+/// Converts a raw log line to text, without its line ending.
+fn decode_line(line: &[u8]) -> String {
+    let line = line.strip_suffix(b"\n").unwrap_or(line);
+    let line = line.strip_suffix(b"\r").unwrap_or(line);
+    String::from_utf8_lossy(line).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stdout(text: &str) -> LogOutput {
+        LogOutput::StdOut {
+            message: text.as_bytes().to_vec().into(),
+        }
+    }
+
+    fn stderr(text: &str) -> LogOutput {
+        LogOutput::StdErr {
+            message: text.as_bytes().to_vec().into(),
+        }
+    }
+
+    #[test]
+    fn joins_lines_split_across_frames() {
+        let mut buffer = LogLineBuffer::default();
+        assert!(buffer.push(stdout("hel")).is_empty());
+        assert_eq!(
+            buffer.push(stdout("lo\r\nwor")),
+            vec![(LogStream::Stdout, "hello".to_owned())]
+        );
+        assert_eq!(buffer.finish(), vec![(LogStream::Stdout, "wor".to_owned())]);
+    }
+
+    #[test]
+    fn keeps_streams_apart() {
+        let mut buffer = LogLineBuffer::default();
+        assert!(buffer.push(stdout("partial ")).is_empty());
+        assert_eq!(buffer.push(stderr("error\n")), vec![(LogStream::Stderr, "error".to_owned())]);
+        assert_eq!(
+            buffer.push(stdout("line\n")),
+            vec![(LogStream::Stdout, "partial line".to_owned())]
+        );
+        assert!(buffer.finish().is_empty());
+    }
+
+    #[test]
+    fn keeps_characters_split_across_frames() {
+        let mut buffer = LogLineBuffer::default();
+        let bytes = "é\n".as_bytes();
+        assert!(
+            buffer
+                .push(LogOutput::StdOut {
+                    message: bytes[..1].to_vec().into()
+                })
+                .is_empty()
+        );
+        let lines = buffer.push(LogOutput::StdOut {
+            message: bytes[1..].to_vec().into(),
+        });
+        assert_eq!(lines, vec![(LogStream::Stdout, "é".to_owned())]);
+    }
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+/// A call made to a `MockEngine`, recorded with its arguments
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MockCall {
+    /// `ContainerEngine::pull_image`
+    PullImage {
+        /// Image reference passed to the call
+        image: String,
+    },
+    /// `ContainerEngine::list_containers`
+    ListContainers,
+    /// `ContainerEngine::build_container`
+    BuildContainer {
+        /// Image reference passed to the call
+        image: String,
+        /// Container name passed to the call
+        container: String,
+    },
+    /// `ContainerEngine::start_container`
+    StartContainer {
+        /// Container name or ID passed to the call
+        container: String,
+    },
+    /// `ContainerEngine::stop_container`
+    StopContainer {
+        /// Container name or ID passed to the call
+        container: String,
+    },
+    /// `ContainerEngine::remove_container`
+    RemoveContainer {
+        /// Container name or ID passed to the call
+        container: String,
+    },
+    /// `ContainerEngine::get_resource_status`
+    GetResourceStatus {
+        /// Image reference passed to the call
+        image: String,
+        /// Container name or ID passed to the call
+        container: String,
+    },
+    /// `ContainerEngine::get_container_metrics`
+    GetContainerMetrics {
+        /// Container name or ID passed to the call
+        container: String,
+    },
+}
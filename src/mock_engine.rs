@@ -0,0 +1,189 @@
+use bollard::models::ContainerSummary;
+use std::{
+    collections::VecDeque,
+    sync::{Mutex, PoisonError},
+};
+
+use crate::{
+    anchor_error::AnchorResult, container_engine::ContainerEngine, container_filter::ContainerFilter,
+    container_metrics::ContainerMetrics, container_spec::ContainerSpec, mock_call::MockCall, pull_outcome::PullOutcome,
+    resource_status::ResourceStatus,
+};
+
+/// In-memory `ContainerEngine` for unit tests, recording every call and returning scripted responses
+///
+/// Responses are queued per operation with the `with_*` methods and returned in order. Once an operation's
+/// queue is empty it succeeds with a default: an up-to-date pull, no containers, the container name as its
+/// ID, `ResourceStatus::Missing`, and empty metrics.
+#[derive(Debug, Default)]
+pub struct MockEngine {
+    /// Calls made so far, in order
+    calls: Mutex<Vec<MockCall>>,
+    /// Scripted responses to `pull_image`
+    pull_responses: Mutex<VecDeque<AnchorResult<PullOutcome>>>,
+    /// Scripted responses to `list_containers`
+    list_responses: Mutex<VecDeque<AnchorResult<Vec<ContainerSummary>>>>,
+    /// Scripted responses to `build_container`
+    build_responses: Mutex<VecDeque<AnchorResult<String>>>,
+    /// Scripted responses to `start_container`
+    start_responses: Mutex<VecDeque<AnchorResult<()>>>,
+    /// Scripted responses to `stop_container`
+    stop_responses: Mutex<VecDeque<AnchorResult<()>>>,
+    /// Scripted responses to `remove_container`
+    remove_responses: Mutex<VecDeque<AnchorResult<()>>>,
+    /// Scripted responses to `get_resource_status`
+    status_responses: Mutex<VecDeque<AnchorResult<ResourceStatus>>>,
+    /// Scripted responses to `get_container_metrics`
+    metrics_responses: Mutex<VecDeque<AnchorResult<ContainerMetrics>>>,
+}
+
+impl MockEngine {
+    /// Create a new `MockEngine` with no scripted responses
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a response to `pull_image`
+    #[must_use]
+    pub fn with_pull_response(self, response: AnchorResult<PullOutcome>) -> Self {
+        push(&self.pull_responses, response);
+        self
+    }
+
+    /// Queue a response to `list_containers`
+    #[must_use]
+    pub fn with_list_response(self, response: AnchorResult<Vec<ContainerSummary>>) -> Self {
+        push(&self.list_responses, response);
+        self
+    }
+
+    /// Queue a response to `build_container`
+    #[must_use]
+    pub fn with_build_response(self, response: AnchorResult<String>) -> Self {
+        push(&self.build_responses, response);
+        self
+    }
+
+    /// Queue a response to `start_container`
+    #[must_use]
+    pub fn with_start_response(self, response: AnchorResult<()>) -> Self {
+        push(&self.start_responses, response);
+        self
+    }
+
+    /// Queue a response to `stop_container`
+    #[must_use]
+    pub fn with_stop_response(self, response: AnchorResult<()>) -> Self {
+        push(&self.stop_responses, response);
+        self
+    }
+
+    /// Queue a response to `remove_container`
+    #[must_use]
+    pub fn with_remove_response(self, response: AnchorResult<()>) -> Self {
+        push(&self.remove_responses, response);
+        self
+    }
+
+    /// Queue a response to `get_resource_status`
+    #[must_use]
+    pub fn with_status_response(self, response: AnchorResult<ResourceStatus>) -> Self {
+        push(&self.status_responses, response);
+        self
+    }
+
+    /// Queue a response to `get_container_metrics`
+    #[must_use]
+    pub fn with_metrics_response(self, response: AnchorResult<ContainerMetrics>) -> Self {
+        push(&self.metrics_responses, response);
+        self
+    }
+
+    /// Returns the calls made so far, in order
+    #[must_use]
+    pub fn calls(&self) -> Vec<MockCall> {
+        self.calls.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    /// Records a call and returns the next scripted response, or `default` if none is queued
+    fn respond<T>(&self, call: MockCall, responses: &Mutex<VecDeque<AnchorResult<T>>>, default: T) -> AnchorResult<T> {
+        self.calls.lock().unwrap_or_else(PoisonError::into_inner).push(call);
+        responses
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .pop_front()
+            .unwrap_or(Ok(default))
+    }
+}
+
+impl ContainerEngine for MockEngine {
+    async fn pull_image(&self, image_reference: &str) -> AnchorResult<PullOutcome> {
+        let call = MockCall::PullImage {
+            image: image_reference.to_string(),
+        };
+        let up_to_date = PullOutcome {
+            already_up_to_date: true,
+            ..PullOutcome::default()
+        };
+        self.respond(call, &self.pull_responses, up_to_date)
+    }
+
+    async fn list_containers(&self, _filter: &ContainerFilter) -> AnchorResult<Vec<ContainerSummary>> {
+        self.respond(MockCall::ListContainers, &self.list_responses, Vec::new())
+    }
+
+    async fn build_container(
+        &self,
+        image_reference: &str,
+        container_name: &str,
+        _spec: &ContainerSpec,
+    ) -> AnchorResult<String> {
+        let call = MockCall::BuildContainer {
+            image: image_reference.to_string(),
+            container: container_name.to_string(),
+        };
+        self.respond(call, &self.build_responses, container_name.to_string())
+    }
+
+    async fn start_container(&self, container_name_or_id: &str) -> AnchorResult<()> {
+        let call = MockCall::StartContainer {
+            container: container_name_or_id.to_string(),
+        };
+        self.respond(call, &self.start_responses, ())
+    }
+
+    async fn stop_container(&self, container_name_or_id: &str) -> AnchorResult<()> {
+        let call = MockCall::StopContainer {
+            container: container_name_or_id.to_string(),
+        };
+        self.respond(call, &self.stop_responses, ())
+    }
+
+    async fn remove_container(&self, container_name_or_id: &str) -> AnchorResult<()> {
+        let call = MockCall::RemoveContainer {
+            container: container_name_or_id.to_string(),
+        };
+        self.respond(call, &self.remove_responses, ())
+    }
+
+    async fn get_resource_status(&self, image_reference: &str, container_name_or_id: &str) -> AnchorResult<ResourceStatus> {
+        let call = MockCall::GetResourceStatus {
+            image: image_reference.to_string(),
+            container: container_name_or_id.to_string(),
+        };
+        self.respond(call, &self.status_responses, ResourceStatus::Missing)
+    }
+
+    async fn get_container_metrics(&self, container_name_or_id: &str) -> AnchorResult<ContainerMetrics> {
+        let call = MockCall::GetContainerMetrics {
+            container: container_name_or_id.to_string(),
+        };
+        self.respond(call, &self.metrics_responses, ContainerMetrics::new())
+    }
+}
+
+/// Appends a scripted response to the back of a queue.
+fn push<T>(responses: &Mutex<VecDeque<T>>, response: T) {
+    responses.lock().unwrap_or_else(PoisonError::into_inner).push_back(response);
+}
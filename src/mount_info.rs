@@ -0,0 +1,50 @@
+use bollard::models::MountPoint;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result};
+
+use crate::bind_propagation::BindPropagation;
+
+/// A mount attached to an existing container, as reported by Docker
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MountInfo {
+    /// Type of the mount, e.g. "bind", "volume" or "tmpfs"
+    pub mount_type: String,
+    /// Name of the volume (volume mounts only)
+    pub name: Option<String>,
+    /// Host path of the bind mount, or of the volume's data
+    pub source: Option<String>,
+    /// Path the mount is attached to inside the container
+    pub destination: String,
+    /// Volume driver that manages the volume (volume mounts only)
+    pub driver: Option<String>,
+    /// Whether the mount is read-only
+    pub read_only: bool,
+    /// Mount propagation mode (bind mounts only)
+    pub propagation: Option<BindPropagation>,
+}
+
+impl From<MountPoint> for MountInfo {
+    fn from(mount: MountPoint) -> Self {
+        let non_empty = |value: Option<String>| value.filter(|value| !value.is_empty());
+        Self {
+            mount_type: mount.typ.map(|typ| typ.to_string()).unwrap_or_default(),
+            name: non_empty(mount.name),
+            source: non_empty(mount.source),
+            destination: mount.destination.unwrap_or_default(),
+            driver: non_empty(mount.driver),
+            read_only: !mount.rw.unwrap_or(true),
+            propagation: mount.propagation.and_then(|propagation| propagation.parse().ok()),
+        }
+    }
+}
+
+impl Display for MountInfo {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        let mode = if self.read_only { "ro" } else { "rw" };
+        let source = self.name.as_ref().or(self.source.as_ref());
+        if let Some(source) = source {
+            write!(fmt, "{source}:")?;
+        }
+        write!(fmt, "{}:{mode} ({})", self.destination, self.mount_type)
+    }
+}
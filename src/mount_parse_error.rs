@@ -0,0 +1,29 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result},
+};
+
+/// Errors that can occur when parsing a Docker volume string (e.g. `/host:/container:ro`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MountParseError {
+    /// The string does not have the `[source:]target[:options]` shape, or the target is not an absolute path
+    InvalidFormat(String),
+    /// The options part contains an unknown or conflicting option
+    InvalidOptions(String),
+    /// The string, or one of its source or target parts, is empty
+    EmptyPath,
+}
+
+impl Display for MountParseError {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::InvalidFormat(volume) => {
+                write!(fmt, "Invalid volume string '{volume}': expected [source:]target[:options]")
+            }
+            Self::InvalidOptions(options) => write!(fmt, "Invalid volume options '{options}'"),
+            Self::EmptyPath => write!(fmt, "Volume string has an empty path"),
+        }
+    }
+}
+
+impl Error for MountParseError {}
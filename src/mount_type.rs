@@ -1,5 +1,10 @@
 use serde::{Deserialize, Serialize};
-use std::fmt::{Display, Formatter, Result};
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Result},
+};
+
+use crate::{bind_propagation::BindPropagation, mount_parse_error::MountParseError, selinux_label::SeLinuxLabel};
 
 /// Represents different types of mounts that can be attached to a container
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -12,6 +17,15 @@ pub enum MountType {
         target: String,
         /// Whether the mount is read-only
         read_only: bool,
+        /// SELinux relabeling to apply to the host content (none if unset)
+        #[serde(default)]
+        selinux_label: Option<SeLinuxLabel>,
+        /// Mount propagation mode (Docker's default, `rprivate`, if unset)
+        #[serde(default)]
+        propagation: Option<BindPropagation>,
+        /// Whether only the source itself is mounted, leaving out mounts nested under it on the host
+        #[serde(default)]
+        non_recursive: bool,
     },
     /// Volume mount: uses a Docker-managed volume
     Volume {
@@ -21,6 +35,15 @@ pub enum MountType {
         target: String,
         /// Whether the mount is read-only
         read_only: bool,
+        /// Volume driver used to create the volume (Docker's default, `local`, if unset)
+        #[serde(default)]
+        driver: Option<String>,
+        /// Driver-specific options passed when the volume is created
+        #[serde(default)]
+        driver_options: HashMap<String, String>,
+        /// Whether to skip copying the image's files at the target into the volume when it is first created
+        #[serde(default)]
+        no_copy: bool,
     },
     /// Anonymous volume: creates a new anonymous volume
     AnonymousVolume {
@@ -33,11 +56,29 @@ pub enum MountType {
 
 impl MountType {
     /// Creates a new bind mount with read-write access
+    ///
+    /// The source should look like a path (e.g. `./data` rather than `data`), as Docker's `-v` format reads a
+    /// bare name as a named volume.
     pub fn bind<S: Into<String>, T: Into<String>>(source: S, target: T) -> Self {
         Self::Bind {
             source: source.into(),
             target: target.into(),
             read_only: false,
+            selinux_label: None,
+            propagation: None,
+            non_recursive: false,
+        }
+    }
+
+    /// Creates a new bind mount with read-write access and the given propagation mode
+    pub fn bind_with_propagation<S: Into<String>, T: Into<String>>(source: S, target: T, propagation: BindPropagation) -> Self {
+        Self::Bind {
+            source: source.into(),
+            target: target.into(),
+            read_only: false,
+            selinux_label: None,
+            propagation: Some(propagation),
+            non_recursive: false,
         }
     }
 
@@ -47,6 +88,9 @@ impl MountType {
             source: source.into(),
             target: target.into(),
             read_only: true,
+            selinux_label: None,
+            propagation: None,
+            non_recursive: false,
         }
     }
 
@@ -56,6 +100,9 @@ impl MountType {
             source: source.into(),
             target: target.into(),
             read_only: false,
+            driver: None,
+            driver_options: HashMap::new(),
+            no_copy: false,
         }
     }
 
@@ -65,9 +112,28 @@ impl MountType {
             source: source.into(),
             target: target.into(),
             read_only: true,
+            driver: None,
+            driver_options: HashMap::new(),
+            no_copy: false,
         }
     }
 
+    /// Creates a new volume mount with read-write access, created by a custom volume driver (e.g. NFS or `rclone`)
+    ///
+    /// # Arguments
+    /// * `source` - Name of the Docker volume
+    /// * `target` - Container path to mount to
+    /// * `driver` - Name of the volume driver
+    /// * `options` - Driver-specific options
+    pub fn volume_with_driver<S: Into<String>, T: Into<String>, D: Into<String>>(
+        source: S,
+        target: T,
+        driver: D,
+        options: HashMap<String, String>,
+    ) -> Self {
+        Self::volume(source, target).with_volume_driver(driver, options)
+    }
+
     /// Creates a new anonymous volume with read-write access
     pub fn anonymous_volume<T: Into<String>>(target: T) -> Self {
         Self::AnonymousVolume {
@@ -84,6 +150,192 @@ impl MountType {
         }
     }
 
+    /// Relabels a bind mount's host content for SELinux-enforcing hosts (e.g. RHEL or Fedora).
+    ///
+    /// Has no effect on volume mounts, which Docker labels itself.
+    #[must_use]
+    pub const fn with_selinux_label(mut self, label: SeLinuxLabel) -> Self {
+        if let Self::Bind { selinux_label, .. } = &mut self {
+            *selinux_label = Some(label);
+        }
+        self
+    }
+
+    /// Mounts only a bind mount's source itself, without the mounts nested under it on the host.
+    ///
+    /// Has no effect on volume mounts. Docker cannot combine this with an SELinux label, so
+    /// `ContainerSpec::validation_errors` reports a bind mount with both.
+    #[must_use]
+    pub const fn with_non_recursive(mut self) -> Self {
+        if let Self::Bind { non_recursive, .. } = &mut self {
+            *non_recursive = true;
+        }
+        self
+    }
+
+    /// Returns whether mounts nested under the source are left out (only ever set for bind mounts)
+    #[must_use]
+    pub const fn is_non_recursive(&self) -> bool {
+        match self {
+            Self::Bind { non_recursive, .. } => *non_recursive,
+            Self::Volume { .. } | Self::AnonymousVolume { .. } => false,
+        }
+    }
+
+    /// Sets the driver used to create a named volume, along with its driver-specific options.
+    ///
+    /// Has no effect on bind mounts or anonymous volumes.
+    #[must_use]
+    pub fn with_volume_driver<D: Into<String>>(mut self, volume_driver: D, options: HashMap<String, String>) -> Self {
+        if let Self::Volume {
+            driver, driver_options, ..
+        } = &mut self
+        {
+            *driver = Some(volume_driver.into());
+            *driver_options = options;
+        }
+        self
+    }
+
+    /// Stops Docker copying the image's existing files at the target into a named volume when it is first created.
+    ///
+    /// Useful for shared volumes, e.g. NFS-backed, whose data the image's defaults must not overwrite.
+    /// Has no effect on bind mounts or anonymous volumes.
+    #[must_use]
+    pub const fn with_no_copy(mut self) -> Self {
+        if let Self::Volume { no_copy, .. } = &mut self {
+            *no_copy = true;
+        }
+        self
+    }
+
+    /// Returns whether copying the image's files into the volume is skipped (only ever set for named volumes)
+    #[must_use]
+    pub const fn is_no_copy(&self) -> bool {
+        match self {
+            Self::Volume { no_copy, .. } => *no_copy,
+            Self::Bind { .. } | Self::AnonymousVolume { .. } => false,
+        }
+    }
+
+    /// Returns the volume driver of the mount (only ever set for named volumes)
+    #[must_use]
+    pub fn volume_driver(&self) -> Option<&str> {
+        match self {
+            Self::Volume { driver, .. } => driver.as_deref(),
+            Self::Bind { .. } | Self::AnonymousVolume { .. } => None,
+        }
+    }
+
+    /// Returns the SELinux relabeling applied to the mount (only ever set for bind mounts)
+    #[must_use]
+    pub const fn selinux_label(&self) -> Option<SeLinuxLabel> {
+        match self {
+            Self::Bind { selinux_label, .. } => *selinux_label,
+            Self::Volume { .. } | Self::AnonymousVolume { .. } => None,
+        }
+    }
+
+    /// Returns the propagation mode of the mount (only ever set for bind mounts)
+    #[must_use]
+    pub const fn propagation(&self) -> Option<BindPropagation> {
+        match self {
+            Self::Bind { propagation, .. } => *propagation,
+            Self::Volume { .. } | Self::AnonymousVolume { .. } => None,
+        }
+    }
+
+    /// Parses a mount from Docker's `-v` volume string format, `[source:]target[:options]`.
+    ///
+    /// A source that looks like a path (starting with `/`, `.` or `~`, or containing `/`) gives a bind mount,
+    /// any other source names a volume, and a missing source gives an anonymous volume. The options are a
+    /// comma-separated list of `ro` or `rw`, plus `z` or `Z` and a propagation mode (e.g. `rshared`) for bind
+    /// mounts, or `nocopy` for named volumes.
+    ///
+    /// The output of `Display` parses back to the same mount only if it uses no settings the format cannot
+    /// express: a bind mount's `non_recursive`, or a named volume's `driver` and `driver_options`, which are
+    /// dropped from the string and come back unset. A bind mount whose source does not look like a path,
+    /// e.g. `MountType::bind("data", "/app")`, also comes back as a named volume, so use a path such as
+    /// `./data` for a host directory.
+    ///
+    /// # Errors
+    /// Returns `MountParseError` if the string is empty, malformed, or has unknown options.
+    pub fn from_docker_volume_string(volume: &str) -> std::result::Result<Self, MountParseError> {
+        if volume.is_empty() {
+            return Err(MountParseError::EmptyPath);
+        }
+
+        let parts: Vec<&str> = volume.split(':').collect();
+        // Container targets are absolute, so a trailing part that isn't a path must be the options
+        let (paths, options) = match parts.as_slice() {
+            [paths @ .., options] if parts.len() == 3 || (parts.len() == 2 && !options.starts_with('/')) => {
+                (paths, Some(*options))
+            }
+            paths if paths.len() <= 2 => (paths, None),
+            _ => return Err(MountParseError::InvalidFormat(volume.to_string())),
+        };
+        if paths.iter().any(|path| path.is_empty()) {
+            return Err(MountParseError::EmptyPath);
+        }
+
+        let mut read_only = None;
+        let mut selinux_label = None;
+        let mut propagation = None;
+        let mut no_copy = false;
+        for option in options.map(|options| options.split(',')).into_iter().flatten() {
+            match option {
+                "ro" | "rw" if read_only.is_none() => read_only = Some(option == "ro"),
+                "z" if selinux_label.is_none() => selinux_label = Some(SeLinuxLabel::Shared),
+                "Z" if selinux_label.is_none() => selinux_label = Some(SeLinuxLabel::Private),
+                "nocopy" if !no_copy => no_copy = true,
+                _ if propagation.is_none() && option.parse::<BindPropagation>().is_ok() => {
+                    propagation = option.parse().ok();
+                }
+                _ => return Err(MountParseError::InvalidOptions(options.unwrap_or_default().to_string())),
+            }
+        }
+        let read_only = read_only.unwrap_or(false);
+        if (selinux_label.is_some() || propagation.is_some()) && !is_bind_source(paths) {
+            // Only bind mounts carry an SELinux label or propagation mode
+            return Err(MountParseError::InvalidOptions(options.unwrap_or_default().to_string()));
+        }
+        if no_copy && (paths.len() != 2 || is_bind_source(paths)) {
+            // Only named volumes carry the no-copy flag
+            return Err(MountParseError::InvalidOptions(options.unwrap_or_default().to_string()));
+        }
+
+        match *paths {
+            [target] if target.starts_with('/') => Ok(Self::AnonymousVolume {
+                target: target.to_string(),
+                read_only,
+            }),
+            [source, target] if target.starts_with('/') => {
+                let is_bind = is_bind_source(paths);
+                let (source, target) = (source.to_string(), target.to_string());
+                Ok(if is_bind {
+                    Self::Bind {
+                        source,
+                        target,
+                        read_only,
+                        selinux_label,
+                        propagation,
+                        non_recursive: false,
+                    }
+                } else {
+                    Self::Volume {
+                        source,
+                        target,
+                        read_only,
+                        driver: None,
+                        driver_options: HashMap::new(),
+                        no_copy,
+                    }
+                })
+            }
+            _ => Err(MountParseError::InvalidFormat(volume.to_string())),
+        }
+    }
+
     /// Returns the target path in the container
     #[must_use]
     pub fn target(&self) -> &str {
@@ -121,26 +373,155 @@ impl MountType {
     }
 }
 
+impl TryFrom<&str> for MountType {
+    type Error = MountParseError;
+
+    /// Parses a mount from Docker's `-v` volume string format, see `MountType::from_docker_volume_string`
+    fn try_from(volume: &str) -> std::result::Result<Self, Self::Error> {
+        Self::from_docker_volume_string(volume)
+    }
+}
+
 impl Display for MountType {
+    /// Formats the mount in Docker's `-v` volume string format, leaving out `non_recursive`, the volume driver
+    /// and its options
     fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
-        match self {
-            Self::Bind {
-                source,
-                target,
-                read_only,
-            }
-            | Self::Volume {
-                source,
-                target,
-                read_only,
-            } => {
-                let mode = if *read_only { "ro" } else { "rw" };
-                write!(fmt, "{source}:{target}:{mode}")
+        let mode = if self.is_read_only() { "ro" } else { "rw" };
+        if let Some(source) = self.source() {
+            write!(fmt, "{source}:")?;
+        }
+        write!(fmt, "{}:{mode}", self.target())?;
+        if let Some(label) = self.selinux_label() {
+            write!(fmt, ",{label}")?;
+        }
+        if let Some(propagation) = self.propagation() {
+            write!(fmt, ",{propagation}")?;
+        }
+        if self.is_no_copy() {
+            write!(fmt, ",nocopy")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns true if the source of `[source, target]` paths is a host path rather than a volume name.
+fn is_bind_source(paths: &[&str]) -> bool {
+    matches!(paths, [source, _] if source.starts_with(['/', '.', '~']) || source.contains('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROPAGATIONS: [BindPropagation; 6] = [
+        BindPropagation::RPrivate,
+        BindPropagation::Private,
+        BindPropagation::RShared,
+        BindPropagation::Shared,
+        BindPropagation::RSlave,
+        BindPropagation::Slave,
+    ];
+
+    fn round_trip(mount: &MountType) -> MountType {
+        let volume = mount.to_string();
+        MountType::from_docker_volume_string(&volume).unwrap_or_else(|err| panic!("'{volume}' did not parse: {err}"))
+    }
+
+    #[test]
+    fn bind_mounts_round_trip() {
+        for source in ["/host/data", "./data", "~/data", "data/nested"] {
+            for read_only in [false, true] {
+                for selinux_label in [None, Some(SeLinuxLabel::Shared), Some(SeLinuxLabel::Private)] {
+                    for propagation in PROPAGATIONS.map(Some).into_iter().chain([None]) {
+                        let mount = MountType::Bind {
+                            source: source.to_string(),
+                            target: "/app/data".to_string(),
+                            read_only,
+                            selinux_label,
+                            propagation,
+                            non_recursive: false,
+                        };
+                        assert_eq!(round_trip(&mount), mount);
+                    }
+                }
             }
-            Self::AnonymousVolume { target, read_only } => {
-                let mode = if *read_only { "ro" } else { "rw" };
-                write!(fmt, "{target}:{mode}")
+        }
+    }
+
+    #[test]
+    fn volume_mounts_round_trip() {
+        for read_only in [false, true] {
+            for no_copy in [false, true] {
+                let mount = MountType::Volume {
+                    source: "data".to_string(),
+                    target: "/app/data".to_string(),
+                    read_only,
+                    driver: None,
+                    driver_options: HashMap::new(),
+                    no_copy,
+                };
+                assert_eq!(round_trip(&mount), mount);
             }
         }
+        for mount in [
+            MountType::anonymous_volume("/cache"),
+            MountType::anonymous_volume_ro("/cache"),
+        ] {
+            assert_eq!(round_trip(&mount), mount);
+        }
+    }
+
+    #[test]
+    fn settings_the_format_cannot_express_are_dropped() {
+        let bind = MountType::bind("/host", "/app").with_non_recursive();
+        assert_eq!(round_trip(&bind), MountType::bind("/host", "/app"));
+
+        let options = HashMap::from([("type".to_string(), "nfs".to_string())]);
+        let volume = MountType::volume_with_driver("data", "/app", "local", options);
+        assert_eq!(round_trip(&volume), MountType::volume("data", "/app"));
+
+        assert_eq!(
+            round_trip(&MountType::bind("data", "/app")),
+            MountType::volume("data", "/app")
+        );
+    }
+
+    #[test]
+    fn parses_short_forms() {
+        assert_eq!(
+            MountType::from_docker_volume_string("/cache:ro"),
+            Ok(MountType::anonymous_volume_ro("/cache"))
+        );
+        assert_eq!(
+            MountType::from_docker_volume_string("data:/app"),
+            Ok(MountType::volume("data", "/app"))
+        );
+        assert_eq!(
+            MountType::from_docker_volume_string("/host:/app"),
+            Ok(MountType::bind("/host", "/app"))
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_strings() {
+        for volume in [
+            "",
+            "/a:/b:ro:extra",
+            ":/app",
+            "/host:",
+            "data:app",
+            "/host:/app:ro,rw",
+            "/host:/app:z,Z",
+            "/host:/app:bogus",
+            "/host:/app:nocopy",
+            "data:/app:z",
+            "data:/app:rshared",
+            "/cache:nocopy",
+        ] {
+            assert!(
+                MountType::from_docker_volume_string(volume).is_err(),
+                "'{volume}' should not parse"
+            );
+        }
     }
 }
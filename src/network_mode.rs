@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Display, Formatter, Result},
+    str::FromStr,
+};
+
+/// Network a container is attached to, as selected with `docker run --network`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NetworkMode {
+    /// Docker's default bridge network
+    #[default]
+    Bridge,
+    /// The host's network stack, with no isolation and no port mapping
+    Host,
+    /// No networking, only a loopback interface
+    None,
+    /// The network stack of another container, given by name or ID
+    Container(String),
+    /// A user-defined network, given by name
+    Custom(String),
+}
+
+impl NetworkMode {
+    /// Returns true if the network mode does not support publishing container ports
+    #[must_use]
+    pub const fn ignores_port_mappings(&self) -> bool {
+        matches!(self, Self::Host | Self::Container(_))
+    }
+}
+
+impl FromStr for NetworkMode {
+    type Err = String;
+
+    /// Parses a network mode as given to `docker run --network`, e.g. "host" or "container:db"
+    fn from_str(mode: &str) -> std::result::Result<Self, Self::Err> {
+        match mode {
+            "" => Err("Network mode must not be empty".to_string()),
+            "bridge" | "default" => Ok(Self::Bridge),
+            "host" => Ok(Self::Host),
+            "none" => Ok(Self::None),
+            _ => Ok(mode.strip_prefix("container:").map_or_else(
+                || Self::Custom(mode.to_string()),
+                |container| Self::Container(container.to_string()),
+            )),
+        }
+    }
+}
+
+impl Display for NetworkMode {
+    /// Formats the network mode as expected by the Docker API
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Bridge => write!(fmt, "bridge"),
+            Self::Host => write!(fmt, "host"),
+            Self::None => write!(fmt, "none"),
+            Self::Container(container) => write!(fmt, "container:{container}"),
+            Self::Custom(network) => write!(fmt, "{network}"),
+        }
+    }
+}
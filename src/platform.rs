@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result};
+
+/// Operating system and CPU architecture of a Docker host
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Platform {
+    /// Operating system (e.g., "linux", "windows")
+    pub os: String,
+    /// CPU architecture (e.g., "amd64", "arm64")
+    pub arch: String,
+}
+
+impl Platform {
+    /// Creates a new platform from an operating system and architecture
+    pub fn new<S: Into<String>, T: Into<String>>(os: S, arch: T) -> Self {
+        Self {
+            os: os.into(),
+            arch: arch.into(),
+        }
+    }
+
+    /// Returns true if the architecture is an ARM variant (e.g., "arm64", "aarch64", "armv7l")
+    #[must_use]
+    pub fn is_arm(&self) -> bool {
+        let arch = self.arch.to_ascii_lowercase();
+        arch.starts_with("arm") || arch.starts_with("aarch")
+    }
+
+    /// Returns true if the operating system is Linux
+    #[must_use]
+    pub fn is_linux(&self) -> bool {
+        self.os.eq_ignore_ascii_case("linux")
+    }
+}
+
+impl Display for Platform {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        write!(fmt, "{}/{}", self.os, self.arch)
+    }
+}
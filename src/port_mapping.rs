@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Display, Formatter, Result},
+    net::IpAddr,
+};
+
+/// Transport protocol of an exposed container port
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Protocol {
+    /// Transmission Control Protocol
+    #[default]
+    Tcp,
+    /// User Datagram Protocol
+    Udp,
+}
+
+impl Protocol {
+    /// Returns the protocol as a string for Docker API port keys (e.g. "80/tcp")
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Tcp => "tcp",
+            Self::Udp => "udp",
+        }
+    }
+}
+
+impl Display for Protocol {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        write!(fmt, "{}", self.as_str())
+    }
+}
+
+/// Mapping of a container port to a port on the Docker host
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PortMapping {
+    /// Port exposed inside the container
+    pub container_port: u16,
+    /// Port published on the host
+    pub host_port: u16,
+    /// Transport protocol of the port (TCP if unspecified)
+    #[serde(default)]
+    pub protocol: Protocol,
+    /// Host interface to bind to, e.g. `127.0.0.1` for localhost only (all interfaces if unset)
+    #[serde(default)]
+    pub host_ip: Option<IpAddr>,
+}
+
+impl PortMapping {
+    /// Creates a new TCP port mapping
+    #[must_use]
+    pub const fn tcp(container_port: u16, host_port: u16) -> Self {
+        Self {
+            container_port,
+            host_port,
+            protocol: Protocol::Tcp,
+            host_ip: None,
+        }
+    }
+
+    /// Creates a new UDP port mapping
+    #[must_use]
+    pub const fn udp(container_port: u16, host_port: u16) -> Self {
+        Self {
+            container_port,
+            host_port,
+            protocol: Protocol::Udp,
+            host_ip: None,
+        }
+    }
+
+    /// Binds the host side of the mapping to a specific interface instead of all interfaces
+    #[must_use]
+    pub const fn with_host_ip(mut self, host_ip: IpAddr) -> Self {
+        self.host_ip = Some(host_ip);
+        self
+    }
+
+    /// Returns true if the host port is a privileged port (below 1024), which usually requires root to bind
+    #[must_use]
+    pub const fn is_privileged(&self) -> bool {
+        self.host_port != 0 && self.host_port < 1024
+    }
+
+    /// Returns the Docker API key for the container side of the mapping (e.g. "53/udp")
+    #[must_use]
+    pub fn container_port_key(&self) -> String {
+        format!("{}/{}", self.container_port, self.protocol)
+    }
+}
+
+impl From<(u16, u16)> for PortMapping {
+    /// Converts a `(container_port, host_port)` pair into a TCP port mapping
+    fn from((container_port, host_port): (u16, u16)) -> Self {
+        Self::tcp(container_port, host_port)
+    }
+}
+
+impl Display for PortMapping {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        match self.host_ip {
+            Some(IpAddr::V4(ip)) => write!(fmt, "{ip}:")?,
+            Some(IpAddr::V6(ip)) => write!(fmt, "[{ip}]:")?,
+            None => {}
+        }
+        write!(fmt, "{}:{}/{}", self.host_port, self.container_port, self.protocol)
+    }
+}
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Result},
+};
+
+/// Column titles that may hold the process ID
+const PID_COLUMNS: &[&str] = &["PID"];
+/// Column titles that may hold the owning user
+const USER_COLUMNS: &[&str] = &["USER", "UID"];
+/// Column titles that may hold the CPU usage
+const CPU_COLUMNS: &[&str] = &["%CPU", "C", "CPU"];
+/// Column titles that may hold the command line
+const COMMAND_COLUMNS: &[&str] = &["CMD", "COMMAND", "ARGS", "Name"];
+
+/// A process running inside a container, as reported by `docker top`
+///
+/// The daemon's columns depend on the host platform and `ps` arguments, so the common fields are
+/// extracted where a recognised column is present, and every column is kept in `columns`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProcessInfo {
+    /// Process ID on the host
+    pub pid: Option<u32>,
+    /// User (name or UID) the process runs as
+    pub user: Option<String>,
+    /// CPU usage as reported by `ps` (a percentage, or a utilisation figure for the `C` column)
+    pub cpu: Option<f64>,
+    /// Command line of the process
+    pub command: Option<String>,
+    /// All columns reported by the daemon, keyed by column title
+    pub columns: HashMap<String, String>,
+}
+
+impl ProcessInfo {
+    /// Builds a process from a row of `docker top` output and the corresponding column titles
+    #[must_use]
+    pub fn from_row(titles: &[String], row: &[String]) -> Self {
+        let columns: HashMap<String, String> = titles.iter().cloned().zip(row.iter().cloned()).collect();
+        let find = |candidates: &[&str]| {
+            candidates
+                .iter()
+                .find_map(|title| columns.get(*title))
+                .map(|value| value.trim().to_string())
+        };
+
+        Self {
+            pid: find(PID_COLUMNS).and_then(|pid| pid.parse().ok()),
+            user: find(USER_COLUMNS),
+            cpu: find(CPU_COLUMNS).and_then(|cpu| cpu.trim_end_matches('%').parse().ok()),
+            command: find(COMMAND_COLUMNS),
+            columns,
+        }
+    }
+}
+
+impl Display for ProcessInfo {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        let or_unknown = |value: Option<String>| value.unwrap_or_else(|| "?".to_string());
+        write!(
+            fmt,
+            "{} {} {} {}",
+            or_unknown(self.pid.map(|pid| pid.to_string())),
+            or_unknown(self.user.clone()),
+            or_unknown(self.cpu.map(|cpu| format!("{cpu:.1}"))),
+            or_unknown(self.command.clone())
+        )
+    }
+}
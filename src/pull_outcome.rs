@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result};
+
+use crate::format::format_bytes;
+
+/// Summary of an image pull, reporting whether anything was downloaded
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PullOutcome {
+    /// Whether the local image already matched the registry, so nothing was downloaded
+    pub already_up_to_date: bool,
+    /// Number of image layers downloaded
+    pub layers_downloaded: usize,
+    /// Total size of the downloaded layers in bytes
+    pub bytes: u64,
+}
+
+impl PullOutcome {
+    /// Returns true if the pull changed the local image
+    #[must_use]
+    pub const fn is_updated(&self) -> bool {
+        !self.already_up_to_date
+    }
+}
+
+impl Display for PullOutcome {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        if self.already_up_to_date {
+            write!(fmt, "Image is up to date")
+        } else {
+            write!(
+                fmt,
+                "Downloaded {} layers ({})",
+                self.layers_downloaded,
+                format_bytes(self.bytes)
+            )
+        }
+    }
+}
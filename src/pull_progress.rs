@@ -0,0 +1,72 @@
+use bollard::models::{CreateImageInfo, ProgressDetail as DockerProgressDetail};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result};
+
+use crate::format::format_bytes;
+
+/// Byte counts of a layer's download or extraction
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ProgressDetail {
+    /// Bytes processed so far
+    pub current: u64,
+    /// Total bytes to process (0 if unknown)
+    pub total: u64,
+}
+
+impl ProgressDetail {
+    /// Returns the fraction of the layer processed, from 0.0 to 1.0, if the total is known
+    #[must_use]
+    pub fn fraction(&self) -> Option<f64> {
+        (self.total > 0).then(|| (self.current as f64 / self.total as f64).min(1.0))
+    }
+}
+
+impl Display for ProgressDetail {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        write!(fmt, "{} / {}", format_bytes(self.current), format_bytes(self.total))
+    }
+}
+
+/// A progress event reported by Docker while pulling an image
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PullProgress {
+    /// ID of the layer the event refers to (empty for events about the whole image)
+    pub layer_id: String,
+    /// Status message, e.g. "Downloading" or "Pull complete"
+    pub status: String,
+    /// Byte counts of the layer, for download and extraction events
+    pub progress_detail: Option<ProgressDetail>,
+}
+
+impl From<CreateImageInfo> for PullProgress {
+    fn from(info: CreateImageInfo) -> Self {
+        Self {
+            layer_id: info.id.unwrap_or_default(),
+            status: info.status.unwrap_or_default(),
+            progress_detail: info.progress_detail.as_ref().and_then(progress_detail),
+        }
+    }
+}
+
+impl Display for PullProgress {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        if !self.layer_id.is_empty() {
+            write!(fmt, "{}: ", self.layer_id)?;
+        }
+        write!(fmt, "{}", self.status)?;
+        if let Some(detail) = &self.progress_detail {
+            write!(fmt, " ({detail})")?;
+        }
+        Ok(())
+    }
+}
+
+/// Converts Docker's progress detail, returning `None` if it reports neither byte count.
+fn progress_detail(detail: &DockerProgressDetail) -> Option<ProgressDetail> {
+    let current = detail.current.and_then(|current| u64::try_from(current).ok());
+    let total = detail.total.and_then(|total| u64::try_from(total).ok());
+    (current.is_some() || total.is_some()).then(|| ProgressDetail {
+        current: current.unwrap_or(0),
+        total: total.unwrap_or(0),
+    })
+}
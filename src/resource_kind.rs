@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result};
+
+/// Kind of Docker resource an operation acted on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ResourceKind {
+    /// A container
+    Container,
+    /// An image
+    Image,
+    /// A volume
+    Volume,
+    /// A network
+    Network,
+}
+
+impl Display for ResourceKind {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Container => write!(fmt, "container"),
+            Self::Image => write!(fmt, "image"),
+            Self::Volume => write!(fmt, "volume"),
+            Self::Network => write!(fmt, "network"),
+        }
+    }
+}
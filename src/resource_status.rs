@@ -2,7 +2,10 @@ use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter, Result};
 
 /// Represents the status a container can be in during its lifecycle.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Statuses are ordered by lifecycle progress, `Missing < Downloaded < Built < ... < Running`, with every
+/// container state after `Built`, so `status >= ResourceStatus::Built` holds whenever a container exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum ResourceStatus {
     /// Image not available, needs to be downloaded
     Missing,
@@ -10,6 +13,19 @@ pub enum ResourceStatus {
     Downloaded,
     /// Container build completed for the specified container
     Built,
+    /// Container ran and has since stopped with the given exit code
+    Exited {
+        /// Exit code of the container's main process
+        code: i64,
+    },
+    /// Container is in the process of being removed
+    Removing,
+    /// Container is dead and could not be stopped or removed cleanly
+    Dead,
+    /// Container is being restarted by its restart policy
+    Restarting,
+    /// Container processes have been paused
+    Paused,
     /// Container startup completed for the specified container
     Running,
 }
@@ -21,16 +37,16 @@ impl ResourceStatus {
         matches!(self, Self::Missing)
     }
 
-    /// Returns true if the resource is at least available (Available, Built, or Running)
+    /// Returns true if the resource is at least available (Downloaded, or any container state)
     #[must_use]
     pub const fn is_available(&self) -> bool {
-        matches!(self, Self::Downloaded | Self::Built | Self::Running)
+        !self.is_missing()
     }
 
-    /// Returns true if the resource is at least built (Built or Running)
+    /// Returns true if the resource is at least built (a container exists in any state)
     #[must_use]
     pub const fn is_built(&self) -> bool {
-        matches!(self, Self::Built | Self::Running)
+        !matches!(self, Self::Missing | Self::Downloaded)
     }
 
     /// Returns true if the resource is in Running state
@@ -38,6 +54,38 @@ impl ResourceStatus {
     pub const fn is_running(&self) -> bool {
         matches!(self, Self::Running)
     }
+
+    /// Returns true if the container ran and has since stopped, whatever its exit code
+    #[must_use]
+    pub const fn is_exited(&self) -> bool {
+        matches!(self, Self::Exited { .. })
+    }
+
+    /// Returns true if the container's processes are paused
+    #[must_use]
+    pub const fn is_paused(&self) -> bool {
+        matches!(self, Self::Paused)
+    }
+
+    /// Returns true if the container ran and then exited with a non-zero code
+    #[must_use]
+    pub const fn is_failed(&self) -> bool {
+        matches!(self, Self::Exited { code } if *code != 0)
+    }
+
+    /// Returns the status the resource moves to after its next lifecycle step, if any
+    ///
+    /// A missing image is downloaded, then built into a container, which is then started. Exited, paused and
+    /// restarting containers return to `Running`. Running, dead and removing containers have no next step.
+    #[must_use]
+    pub const fn next(&self) -> Option<Self> {
+        match self {
+            Self::Missing => Some(Self::Downloaded),
+            Self::Downloaded => Some(Self::Built),
+            Self::Built | Self::Exited { .. } | Self::Paused | Self::Restarting => Some(Self::Running),
+            Self::Removing | Self::Dead | Self::Running => None,
+        }
+    }
 }
 
 impl Display for ResourceStatus {
@@ -46,6 +94,11 @@ impl Display for ResourceStatus {
             Self::Missing => write!(fmt, "Missing"),
             Self::Downloaded => write!(fmt, "Downloaded"),
             Self::Built => write!(fmt, "Built"),
+            Self::Exited { code } => write!(fmt, "Exited ({code})"),
+            Self::Removing => write!(fmt, "Removing"),
+            Self::Dead => write!(fmt, "Dead"),
+            Self::Restarting => write!(fmt, "Restarting"),
+            Self::Paused => write!(fmt, "Paused"),
             Self::Running => write!(fmt, "Running"),
         }
     }
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result};
+
+/// SELinux relabeling applied to a bind mount's host content on SELinux-enforcing hosts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SeLinuxLabel {
+    /// Shared label (`z`): the content can be used by several containers
+    Shared,
+    /// Private label (`Z`): the content can only be used by this container
+    Private,
+}
+
+impl SeLinuxLabel {
+    /// Returns the Docker volume option for the label
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Shared => "z",
+            Self::Private => "Z",
+        }
+    }
+}
+
+impl Display for SeLinuxLabel {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        write!(fmt, "{}", self.as_str())
+    }
+}
@@ -1,12 +1,28 @@
-use std::process::Command;
-
-use crate::anchor_error::{AnchorError, AnchorResult};
+use bollard::Docker;
+use std::{
+    process::Command,
+    time::{Duration, Instant},
+};
+use tokio::{
+    task::spawn_blocking,
+    time::{sleep, timeout as with_timeout},
+};
+
+use crate::{
+    anchor_error::{AnchorError, AnchorResult},
+    client::Client,
+};
 
 /// Attempts to start the Docker daemon process based on the operating system.
 ///
 /// # Errors
-/// Returns `AnchorError::ConnectionError` if the start command fails.
+/// Returns `AnchorError::DockerNotInstalled` if the `docker` command is not available.
+/// Returns `AnchorError::ConnectionError` if Docker is installed but the start command fails.
 pub fn start_docker_daemon() -> AnchorResult<()> {
+    if !Client::is_docker_installed() {
+        return Err(AnchorError::DockerNotInstalled);
+    }
+
     if cfg!(target_os = "macos") {
         // On macOS, try to start Docker Desktop
         start_docker_macos()
@@ -19,6 +35,53 @@ pub fn start_docker_daemon() -> AnchorResult<()> {
     }
 }
 
+/// Starts the Docker daemon and waits until it accepts connections.
+///
+/// `start_docker_daemon` returns as soon as the start command has been issued, while the daemon
+/// typically takes several seconds before it responds. This polls the daemon with a ping every
+/// `poll_interval` until it answers or `timeout` has elapsed. The start command runs on Tokio's
+/// blocking thread pool, so it does not stall the async runtime.
+///
+/// # Arguments
+/// * `timeout` - Maximum time to wait for the daemon to become available
+/// * `poll_interval` - Time to wait between connection attempts
+///
+/// # Errors
+/// Returns `AnchorError::DockerNotInstalled` if the `docker` command is not available.
+/// Returns `AnchorError::ConnectionError` if the start command fails.
+/// Returns `AnchorError::TimeoutError` if the daemon does not respond within the timeout.
+pub async fn start_docker_daemon_and_wait(timeout: Duration, poll_interval: Duration) -> AnchorResult<()> {
+    // The start commands wait for their processes to exit, so keep them off the async runtime's workers
+    spawn_blocking(start_docker_daemon).await.map_err(|err| {
+        AnchorError::connection_error(format!("Failed to run the Docker start command: {err}")).with_source(err)
+    })??;
+
+    let deadline = Instant::now() + timeout;
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if let Ok(docker) = Docker::connect_with_local_defaults()
+            && matches!(with_timeout(remaining, docker.ping()).await, Ok(Ok(_)))
+        {
+            return Ok(());
+        }
+
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(AnchorError::TimeoutError(format!(
+                "Docker daemon did not become available within {}s",
+                timeout.as_secs()
+            )));
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            remaining_secs = remaining.as_secs(),
+            "Docker daemon not yet available, retrying"
+        );
+        sleep(poll_interval.min(remaining)).await;
+    }
+}
+
 /// Starts Docker Desktop on macOS.
 fn start_docker_macos() -> AnchorResult<()> {
     // Try different possible locations for Docker Desktop
@@ -42,8 +105,8 @@ fn start_docker_macos() -> AnchorResult<()> {
 
     match output {
         Ok(output) if output.status.success() => Ok(()),
-        _ => Err(AnchorError::ConnectionError(
-            "Failed to start Docker on macOS. Please start Docker Desktop manually.".to_string(),
+        _ => Err(AnchorError::connection_error(
+            "Failed to start Docker on macOS. Please start Docker Desktop manually.",
         )),
     }
 }
@@ -74,8 +137,8 @@ fn start_docker_windows() -> AnchorResult<()> {
 
     match output {
         Ok(output) if output.status.success() => Ok(()),
-        _ => Err(AnchorError::ConnectionError(
-            "Failed to start Docker on Windows. Please start Docker Desktop manually.".to_string(),
+        _ => Err(AnchorError::connection_error(
+            "Failed to start Docker on Windows. Please start Docker Desktop manually.",
         )),
     }
 }
@@ -85,28 +148,28 @@ fn start_docker_linux() -> AnchorResult<()> {
     // Try systemctl first (most common on modern Linux)
     let systemctl_output = Command::new("sudo").args(["systemctl", "start", "docker"]).output();
 
-    if let Ok(output) = systemctl_output {
-        if output.status.success() {
-            return Ok(());
-        }
+    if let Ok(output) = systemctl_output
+        && output.status.success()
+    {
+        return Ok(());
     }
 
     // Try service command (older systems)
     let service_output = Command::new("sudo").args(["service", "docker", "start"]).output();
 
-    if let Ok(output) = service_output {
-        if output.status.success() {
-            return Ok(());
-        }
+    if let Ok(output) = service_output
+        && output.status.success()
+    {
+        return Ok(());
     }
 
     // Try direct dockerd command (last resort)
     let dockerd_output = Command::new("sudo").args(["dockerd", "--detach"]).output();
 
     match dockerd_output {
-            Ok(output) if output.status.success() => Ok(()),
-            _ => Err(AnchorError::ConnectionError(
-                "Failed to start Docker on Linux. Please start Docker service manually with 'sudo systemctl start docker' or 'sudo service docker start'.".to_string()
-            )),
-        }
+        Ok(output) if output.status.success() => Ok(()),
+        _ => Err(AnchorError::connection_error(
+            "Failed to start Docker on Linux. Please start Docker service manually with 'sudo systemctl start docker' or 'sudo service docker start'.",
+        )),
+    }
 }
@@ -0,0 +1,263 @@
+use std::net::Ipv6Addr;
+
+/// Maximum length of an image tag accepted by Docker
+const MAX_TAG_LENGTH: usize = 128;
+/// Minimum length of the hex-encoded part of an image digest
+const MIN_DIGEST_LENGTH: usize = 32;
+/// Maximum length of a hostname (RFC 1123)
+const MAX_HOSTNAME_LENGTH: usize = 253;
+/// Maximum length of a single hostname label (RFC 1123)
+const MAX_HOSTNAME_LABEL_LENGTH: usize = 63;
+/// Linux capabilities known to Docker, without the `CAP_` prefix
+const CAPABILITIES: &[&str] = &[
+    "AUDIT_CONTROL",
+    "AUDIT_READ",
+    "AUDIT_WRITE",
+    "BLOCK_SUSPEND",
+    "BPF",
+    "CHECKPOINT_RESTORE",
+    "CHOWN",
+    "DAC_OVERRIDE",
+    "DAC_READ_SEARCH",
+    "FOWNER",
+    "FSETID",
+    "IPC_LOCK",
+    "IPC_OWNER",
+    "KILL",
+    "LEASE",
+    "LINUX_IMMUTABLE",
+    "MAC_ADMIN",
+    "MAC_OVERRIDE",
+    "MKNOD",
+    "NET_ADMIN",
+    "NET_BIND_SERVICE",
+    "NET_BROADCAST",
+    "NET_RAW",
+    "PERFMON",
+    "SETFCAP",
+    "SETGID",
+    "SETPCAP",
+    "SETUID",
+    "SYS_ADMIN",
+    "SYS_BOOT",
+    "SYS_CHROOT",
+    "SYS_MODULE",
+    "SYS_NICE",
+    "SYS_PACCT",
+    "SYS_PTRACE",
+    "SYS_RAWIO",
+    "SYS_RESOURCE",
+    "SYS_TIME",
+    "SYS_TTY_CONFIG",
+    "SYSLOG",
+    "WAKE_ALARM",
+];
+
+/// Checks whether a string is a valid Docker container name.
+///
+/// Docker requires names to match `/?[a-zA-Z0-9][a-zA-Z0-9_.-]+`, so they must be at least two
+/// characters long and cannot contain spaces or slashes, other than the leading `/` Docker adds itself.
+#[must_use]
+pub fn is_valid_container_name(name: &str) -> bool {
+    let name = name.strip_prefix('/').unwrap_or(name);
+    let mut chars = name.chars();
+    name.len() >= 2
+        && chars.next().is_some_and(|c| c.is_ascii_alphanumeric())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+}
+
+/// Checks whether a string is a valid hostname under RFC 1123.
+///
+/// Each dot-separated label must be 1 to 63 ASCII letters, digits or hyphens and cannot start or end
+/// with a hyphen, and the whole name must be at most 253 characters.
+#[must_use]
+pub fn is_valid_hostname(hostname: &str) -> bool {
+    hostname.len() <= MAX_HOSTNAME_LENGTH
+        && hostname.split('.').all(|label| {
+            (1..=MAX_HOSTNAME_LABEL_LENGTH).contains(&label.len())
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+}
+
+/// Checks whether a string names a Linux capability Docker can add or drop, e.g. `NET_ADMIN`.
+///
+/// Names are case-insensitive and may carry the `CAP_` prefix. `ALL` is accepted to add or drop every capability.
+#[must_use]
+pub fn is_valid_capability(name: &str) -> bool {
+    let name = name.to_ascii_uppercase();
+    let name = name.strip_prefix("CAP_").unwrap_or(&name);
+    name == "ALL" || CAPABILITIES.contains(&name)
+}
+
+/// Checks whether a string is a syntactically valid Docker image reference.
+///
+/// Accepts `[registry[:port]/]repository[:tag][@digest]`, where repository path components are
+/// lowercase and the digest has the form `algorithm:hex` (e.g. `sha256:...`).
+/// This only checks the syntax; it does not check that the image exists.
+#[must_use]
+pub fn is_valid_image_reference(reference: &str) -> bool {
+    let (name_and_tag, digest) = reference
+        .split_once('@')
+        .map_or((reference, None), |(name, digest)| (name, Some(digest)));
+    if digest.is_some_and(|digest| !is_valid_digest(digest)) {
+        return false;
+    }
+
+    // A tag follows the last ':' after the final '/', so a registry port is not mistaken for one
+    let last_component_start = name_and_tag.rfind('/').map_or(0, |index| index + 1);
+    let (name, tag) = name_and_tag[last_component_start..]
+        .rfind(':')
+        .map_or((name_and_tag, None), |index| {
+            let split = last_component_start + index;
+            (&name_and_tag[..split], Some(&name_and_tag[split + 1..]))
+        });
+    if tag.is_some_and(|tag| !is_valid_tag(tag)) {
+        return false;
+    }
+
+    let mut components: Vec<&str> = name.split('/').collect();
+    if components.len() > 1 && (components[0].contains(['.', ':']) || components[0] == "localhost") {
+        let registry = components.remove(0);
+        if !is_valid_registry(registry) {
+            return false;
+        }
+    }
+    components.iter().all(|component| is_valid_path_component(component))
+}
+
+/// Checks a repository path component, e.g. `library` or `my-app`.
+fn is_valid_path_component(component: &str) -> bool {
+    let bytes = component.as_bytes();
+    let is_alphanumeric = |byte: &u8| byte.is_ascii_lowercase() || byte.is_ascii_digit();
+    let is_separator = |byte: &u8| matches!(byte, b'.' | b'_' | b'-');
+
+    bytes.first().is_some_and(is_alphanumeric)
+        && bytes.last().is_some_and(is_alphanumeric)
+        && bytes.iter().all(|byte| is_alphanumeric(byte) || is_separator(byte))
+        && !component.contains("___")
+        && bytes
+            .windows(2)
+            .all(|pair| !(is_separator(&pair[0]) && is_separator(&pair[1])) || pair == b"__" || pair == b"--")
+}
+
+/// Checks a registry host with an optional port, e.g. `ghcr.io`, `localhost:5000` or `[::1]:5000`.
+fn is_valid_registry(registry: &str) -> bool {
+    // IPv6 addresses are bracketed, so their colons are not mistaken for the port separator
+    if let Some(rest) = registry.strip_prefix('[') {
+        return rest.split_once(']').is_some_and(|(address, port)| {
+            address.parse::<Ipv6Addr>().is_ok()
+                && (port.is_empty() || port.strip_prefix(':').is_some_and(|port| port.parse::<u16>().is_ok()))
+        });
+    }
+    let (host, port) = registry
+        .split_once(':')
+        .map_or((registry, None), |(host, port)| (host, Some(port)));
+    if port.is_some_and(|port| port.parse::<u16>().is_err()) {
+        return false;
+    }
+    host.split('.').all(|label| {
+        !label.is_empty()
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    })
+}
+
+/// Checks an image tag, e.g. `latest` or `1.25.3-alpine`.
+fn is_valid_tag(tag: &str) -> bool {
+    let mut chars = tag.chars();
+    tag.len() <= MAX_TAG_LENGTH
+        && chars.next().is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+}
+
+/// Checks an image digest, e.g. `sha256:` followed by 64 hex characters.
+fn is_valid_digest(digest: &str) -> bool {
+    digest.split_once(':').is_some_and(|(algorithm, hex)| {
+        !algorithm.is_empty()
+            && algorithm
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '+' | '.' | '_' | '-'))
+            && hex.len() >= MIN_DIGEST_LENGTH
+            && hex.chars().all(|c| c.is_ascii_hexdigit())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIGEST: &str = "sha256:e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+    #[test]
+    fn validates_container_names() {
+        for name in ["ab", "web", "/web", "my-app_1.0", "0web", "A1"] {
+            assert!(is_valid_container_name(name), "'{name}' should be valid");
+        }
+        for name in ["", "a", "/a", "/", "//web", "-web", "_web", ".web", "my app", "a/b", "web!"] {
+            assert!(!is_valid_container_name(name), "'{name}' should be invalid");
+        }
+    }
+
+    #[test]
+    fn accepts_valid_image_references() {
+        let with_digest = format!("repo@{DIGEST}");
+        let with_tag_and_digest = format!("ghcr.io/owner/repo:v1.2@{DIGEST}");
+        for reference in [
+            "nginx",
+            "nginx:latest",
+            "nginx:1.25.3-alpine",
+            "app:V1_rc",
+            "library/nginx",
+            "docker.io/library/nginx:latest",
+            "localhost/app",
+            "localhost:5000/a/b:tag",
+            "registry.example.com:5000/team/app",
+            "Registry.Example.com/app",
+            "123456789012.dkr.ecr.eu-west-2.amazonaws.com/app:latest",
+            "[::1]:5000/app:tag",
+            "[fe80::1]/app",
+            "a__b",
+            "a---b",
+            "a.b_c-d",
+            "a1/b2/c3",
+            &with_digest,
+            &with_tag_and_digest,
+        ] {
+            assert!(is_valid_image_reference(reference), "'{reference}' should be valid");
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_image_references() {
+        let long_tag = format!("app:{}", "a".repeat(MAX_TAG_LENGTH + 1));
+        for reference in [
+            "",
+            "Upper/case",
+            "Nginx",
+            "a.-b",
+            "a-.b",
+            "a_-b",
+            "a___b",
+            "-app",
+            "app-",
+            "/app",
+            "app/",
+            "a//b",
+            "app:",
+            "app:-tag",
+            "app:tag:more",
+            "host:port/app",
+            "[::1/app",
+            "[not-ip]:5000/app",
+            "repo@sha256:xyz",
+            "repo@sha256:abc123",
+            "repo@:e3b0c44298fc1c149afbf4c8996fb924",
+            &long_tag,
+        ] {
+            assert!(!is_valid_image_reference(reference), "'{reference}' should be invalid");
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use bollard::models::Volume;
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Result},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Details of a Docker-managed volume
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VolumeInfo {
+    /// Name of the volume
+    pub name: String,
+    /// Volume driver that manages the volume (e.g., "local")
+    pub driver: String,
+    /// Location of the volume's data on the Docker host
+    pub mountpoint: String,
+    /// Labels attached to the volume
+    pub labels: HashMap<String, String>,
+    /// Driver-specific options the volume was created with
+    pub options: HashMap<String, String>,
+    /// Time the volume was created (if reported by the driver)
+    pub created: Option<SystemTime>,
+}
+
+impl From<Volume> for VolumeInfo {
+    fn from(volume: Volume) -> Self {
+        Self {
+            name: volume.name,
+            driver: volume.driver,
+            mountpoint: volume.mountpoint,
+            labels: volume.labels,
+            options: volume.options,
+            created: volume
+                .created_at
+                .and_then(|created_at| DateTime::parse_from_rfc3339(&created_at).ok())
+                .and_then(|created_at| u64::try_from(created_at.timestamp()).ok())
+                .map(|secs| UNIX_EPOCH + Duration::from_secs(secs)),
+        }
+    }
+}
+
+impl Display for VolumeInfo {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        write!(fmt, "{} ({}) at {}", self.name, self.driver, self.mountpoint)
+    }
+}
@@ -0,0 +1,99 @@
+//! Integration tests for `Client::run_to_completion`, which need a running Docker daemon.
+//!
+//! Run with `cargo test -- --ignored`.
+
+use anchor::prelude::*;
+use std::time::Duration;
+
+/// Small image whose shell can exit with any code
+const IMAGE: &str = "alpine:3.20";
+
+/// Builds a container that runs a shell command and exits
+async fn build_job(client: &Client, name: &str, command: &str, auto_remove: bool) -> String {
+    let _unused = client.remove_container(name).await;
+    let _unused = client.pull_image(IMAGE).await.expect("image should be pulled");
+    let spec = ContainerSpec {
+        args: Some(vec!["sh".to_owned(), "-c".to_owned(), command.to_owned()]),
+        auto_remove,
+        ..ContainerSpec::new()
+    };
+    client
+        .build_container_with_spec(IMAGE, name, &spec)
+        .await
+        .expect("container should be built")
+}
+
+#[tokio::test]
+#[ignore = "requires a running Docker daemon"]
+async fn fast_job_reports_exited_status() {
+    let client = Client::new_anonymous().await.expect("client should connect");
+    let name = "anchor-test-run-to-completion";
+    let _unused = build_job(&client, name, "exit 0", false).await;
+
+    client.run_to_completion(name).await.expect("job should succeed");
+    let status = client.get_resource_status(IMAGE, name).await.expect("status should be read");
+    assert_eq!(status, ResourceStatus::Exited { code: 0 });
+
+    client.remove_container(name).await.expect("container should be removed");
+}
+
+#[tokio::test]
+#[ignore = "requires a running Docker daemon"]
+async fn failing_job_reports_exit_code() {
+    let client = Client::new_anonymous().await.expect("client should connect");
+    let name = "anchor-test-run-to-completion-failure";
+    let _unused = build_job(&client, name, "exit 3", false).await;
+
+    let err = client.run_to_completion(name).await.expect_err("job should fail");
+    assert!(err.to_string().contains("code 3"), "unexpected error: {err}");
+    let status = client.get_resource_status(IMAGE, name).await.expect("status should be read");
+    assert_eq!(status, ResourceStatus::Exited { code: 3 });
+
+    client.remove_container(name).await.expect("container should be removed");
+}
+
+#[tokio::test]
+#[ignore = "requires a running Docker daemon"]
+async fn auto_removed_job_reports_missing_container() {
+    let client = Client::new_anonymous().await.expect("client should connect");
+    let name = "anchor-test-run-to-completion-auto-remove";
+    let _unused = build_job(&client, name, "exit 0", true).await;
+
+    client.run_to_completion(name).await.expect("job should succeed");
+    let status = client.get_resource_status(IMAGE, name).await.expect("status should be read");
+    assert_eq!(status, ResourceStatus::Downloaded);
+    assert!(!client.container_exists(name).await.expect("containers should be listed"));
+}
+
+#[tokio::test]
+#[ignore = "requires a running Docker daemon"]
+async fn fast_job_passes_startup_check() {
+    let client = Client::builder()
+        .startup_check(Duration::from_secs(2), 20)
+        .build()
+        .await
+        .expect("client should connect");
+    let name = "anchor-test-run-to-completion-startup-check";
+    let _unused = build_job(&client, name, "exit 0", false).await;
+
+    client.run_to_completion(name).await.expect("job should succeed");
+    let status = client.get_resource_status(IMAGE, name).await.expect("status should be read");
+    assert_eq!(status, ResourceStatus::Exited { code: 0 });
+
+    client.remove_container(name).await.expect("container should be removed");
+}
+
+#[tokio::test]
+#[ignore = "requires a running Docker daemon"]
+async fn auto_removed_job_passes_startup_check() {
+    let client = Client::builder()
+        .startup_check(Duration::from_secs(2), 20)
+        .build()
+        .await
+        .expect("client should connect");
+    let name = "anchor-test-run-to-completion-startup-check-auto-remove";
+    let _unused = build_job(&client, name, "exit 0", true).await;
+
+    client.run_to_completion(name).await.expect("job should succeed");
+    assert!(!client.container_exists(name).await.expect("containers should be listed"));
+}